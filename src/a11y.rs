@@ -0,0 +1,231 @@
+//! Accessibility checks
+//!
+//! [`Document::check_a11y`] and [`Element::check_a11y`] report common accessibility issues,
+//! each identified by a [`Rule`], so that tests can assert that a page has no finding.
+//!
+//! ## Example
+//!
+//! ```
+//! # use fun_html::{attr, elt, html, a11y::{Finding, Rule}};
+//! let doc = html([attr::lang("en")], [elt::head([], []), elt::body([], [
+//!     elt::img([attr::src("/logo.png")]),
+//! ])]);
+//!
+//! assert_eq!(
+//!     doc.check_a11y(),
+//!     [Finding { rule: Rule::ImgAlt, path: "html > body > img".into() }],
+//! );
+//! ```
+//!
+//! [`Element::accessibility_tree`] computes the roles and accessible names exposed to assistive technologies,
+//! so that tests can query elements the way users perceive them (see [`AccessibilityTree`]).
+
+mod tree;
+
+use alloc::{fmt::Display, string::String, vec::Vec};
+
+pub use tree::{AccessibilityTree, AccessibleNode};
+
+use crate::{
+    normalize::{nodes, paths, Node},
+    Document, Element,
+};
+
+impl Document {
+    /// Check the document for common accessibility issues
+    ///
+    /// See the [`a11y`](crate::a11y) module
+    pub fn check_a11y(&self) -> Vec<Finding> {
+        self.0.check_a11y()
+    }
+}
+
+impl Element {
+    /// Check the element tree for common accessibility issues
+    ///
+    /// See the [`a11y`](crate::a11y) module
+    pub fn check_a11y(&self) -> Vec<Finding> {
+        let nodes = nodes(self);
+        let mut checker = Checker {
+            findings: Vec::new(),
+            context: tree::Context::new(&nodes),
+            previous_heading_level: None,
+        };
+        checker.check(&nodes, "", false);
+        checker.findings
+    }
+}
+
+/// An accessibility issue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// Rule that is violated
+    pub rule: Rule,
+    /// Path of the offending element (e.g. `html > body > img`)
+    pub path: String,
+}
+
+/// Accessibility rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Rule {
+    /// `img` elements must have an `alt` attribute (which may be empty for decorative images)
+    ImgAlt,
+    /// Form controls must have a label, either with a `label` element (via `for` or by nesting),
+    /// or with an `aria-label` or `aria-labelledby` attribute
+    ControlLabel,
+    /// Heading levels must not be skipped (e.g. `h1` followed by `h3`)
+    HeadingOrder,
+    /// The `html` element must have a `lang` attribute
+    HtmlLang,
+    /// Links must have a text content, or an accessible name
+    EmptyLink,
+    /// Buttons must have a text content, or an accessible name
+    EmptyButton,
+    /// Tables must have header cells (`th`)
+    TableHeader,
+}
+
+impl Rule {
+    /// Identifier of the rule (e.g. `img-alt`)
+    pub fn id(self) -> &'static str {
+        match self {
+            Rule::ImgAlt => "img-alt",
+            Rule::ControlLabel => "control-label",
+            Rule::HeadingOrder => "heading-order",
+            Rule::HtmlLang => "html-lang",
+            Rule::EmptyLink => "empty-link",
+            Rule::EmptyButton => "empty-button",
+            Rule::TableHeader => "table-header",
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let message = match self.rule {
+            Rule::ImgAlt => "image without `alt` attribute",
+            Rule::ControlLabel => "form control without label",
+            Rule::HeadingOrder => "skipped heading level",
+            Rule::HtmlLang => "document without `lang` attribute",
+            Rule::EmptyLink => "link without text",
+            Rule::EmptyButton => "button without text",
+            Rule::TableHeader => "table without header cell",
+        };
+        write!(f, "{}: {message} ({})", self.path, self.rule)
+    }
+}
+
+struct Checker<'a> {
+    findings: Vec<Finding>,
+    context: tree::Context<'a>,
+    previous_heading_level: Option<u8>,
+}
+
+impl<'a> Checker<'a> {
+    fn check(&mut self, nodes: &'a [Node<'a>], parent_path: &str, in_label: bool) {
+        for (path, node) in paths(parent_path, nodes).iter().zip(nodes) {
+            let tag = match node.tag() {
+                Some(tag) => tag,
+                None => continue,
+            };
+            let rule = match tag {
+                "img" if node.attribute("alt").is_none() => Some(Rule::ImgAlt),
+                "input" | "select" | "textarea"
+                    if is_labelable(node) && !in_label && !self.is_labelled(node) =>
+                {
+                    Some(Rule::ControlLabel)
+                }
+                "html" if node.attribute("lang").map_or(true, str::is_empty) => {
+                    Some(Rule::HtmlLang)
+                }
+                "a" if !has_accessible_name(node) => Some(Rule::EmptyLink),
+                "button" if !has_accessible_name(node) => Some(Rule::EmptyButton),
+                "table" if !has_descendant(node, "th") => Some(Rule::TableHeader),
+                _ => self.check_heading_order(tag),
+            };
+            if let Some(rule) = rule {
+                self.findings.push(Finding {
+                    rule,
+                    path: path.clone(),
+                });
+            }
+            if let Node::Parent { children, .. } = node {
+                self.check(children, path, in_label || tag == "label");
+            }
+        }
+    }
+
+    fn is_labelled(&self, node: &Node<'_>) -> bool {
+        self.context.has_label(node) || has_aria_label(node)
+    }
+
+    fn check_heading_order(&mut self, tag: &str) -> Option<Rule> {
+        let level = match tag.as_bytes() {
+            [b'h', level @ b'1'..=b'6'] => level - b'0',
+            _ => return None,
+        };
+        let skipped = self
+            .previous_heading_level
+            .map_or(false, |previous| level > previous + 1);
+        self.previous_heading_level = Some(level);
+        if skipped {
+            Some(Rule::HeadingOrder)
+        } else {
+            None
+        }
+    }
+}
+
+fn is_labelable(node: &Node<'_>) -> bool {
+    node.tag() != Some("input")
+        || !matches!(
+            node.attribute("type")
+                .map(str::to_ascii_lowercase)
+                .as_deref(),
+            Some("hidden" | "submit" | "reset" | "button" | "image")
+        )
+}
+
+fn has_aria_label(node: &Node<'_>) -> bool {
+    ["aria-label", "aria-labelledby"]
+        .iter()
+        .any(|name| node.attribute(name).map_or(false, |v| !v.trim().is_empty()))
+}
+
+/// Returns `true` if the node has a non-empty text content, or an accessible name
+fn has_accessible_name(node: &Node<'_>) -> bool {
+    match node {
+        Node::Text(text) => !text.trim().is_empty(),
+        Node::Raw(_) => true,
+        Node::Script(_) => false,
+        Node::Parent { .. } | Node::Void { .. }
+            if has_aria_label(node)
+                || node
+                    .attribute("title")
+                    .map_or(false, |t| !t.trim().is_empty()) =>
+        {
+            true
+        }
+        Node::Void { tag: "img", .. } => node
+            .attribute("alt")
+            .map_or(false, |alt| !alt.trim().is_empty()),
+        Node::Void { .. } => false,
+        Node::Parent { children, .. } => children.iter().any(has_accessible_name),
+    }
+}
+
+fn has_descendant(node: &Node<'_>, tag: &str) -> bool {
+    match node {
+        Node::Parent { children, .. } => children
+            .iter()
+            .any(|child| child.tag() == Some(tag) || has_descendant(child, tag)),
+        _ => false,
+    }
+}
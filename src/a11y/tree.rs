@@ -0,0 +1,498 @@
+use alloc::{
+    fmt::Display,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    normalize::{nodes, paths, visit, Node},
+    Document, Element,
+};
+
+impl Document {
+    /// Compute the accessibility tree of the document
+    ///
+    /// See [`AccessibilityTree`]
+    pub fn accessibility_tree(&self) -> AccessibilityTree {
+        self.0.accessibility_tree()
+    }
+}
+
+impl Element {
+    /// Compute the accessibility tree of the element
+    ///
+    /// See [`AccessibilityTree`]
+    pub fn accessibility_tree(&self) -> AccessibilityTree {
+        let nodes = nodes(self);
+        let context = Context::new(&nodes);
+        let mut roots = Vec::new();
+        context.build(&mut roots, &nodes, "", None, false);
+        AccessibilityTree(roots)
+    }
+}
+
+/// Accessibility tree, as exposed to assistive technologies
+///
+/// Each element gets its explicit role (from the `role` attribute) or its implicit role (from its tag),
+/// and an accessible name computed from `aria-labelledby`, `aria-label`, associated labels,
+/// `alt` attributes, text content and `title` attributes.
+///
+/// Elements without a role (like `div` and `span`), with the `none` or `presentation` role,
+/// are omitted, and their children are attached to the nearest ancestor having a role.
+/// Hidden elements (with `hidden` or `aria-hidden="true"`) are omitted with their descendants.
+///
+/// This is a simplification of the [HTML-AAM](https://www.w3.org/TR/html-aam-1.0/)
+/// and [accname](https://www.w3.org/TR/accname-1.2/) specifications, meant to write tests
+/// in terms of what users perceive.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr, elt};
+/// let element = elt::form([], [
+///     elt::label([attr::for_("email")], ["Email".into()]),
+///     elt::input([attr::id("email"), attr::type_email()]),
+///     elt::button([attr::type_submit()], ["Save".into()]),
+/// ]);
+///
+/// let tree = element.accessibility_tree();
+///
+/// assert_eq!(tree.get_by_role("textbox", "Email").path, "form > input");
+/// assert_eq!(tree.to_string(), "form\n  textbox \"Email\"\n  button \"Save\"");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessibilityTree(Vec<AccessibleNode>);
+
+/// Node of an [`AccessibilityTree`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessibleNode {
+    /// Role (e.g. `button`)
+    pub role: String,
+    /// Accessible name (empty if the node has no name)
+    pub name: String,
+    /// Path of the element (e.g. `html > body > form > button`)
+    pub path: String,
+    /// Children
+    pub children: Vec<AccessibleNode>,
+}
+
+impl AccessibilityTree {
+    /// Top-level nodes
+    pub fn roots(&self) -> &[AccessibleNode] {
+        &self.0
+    }
+
+    /// Iterate over all nodes, in document order
+    pub fn iter(&self) -> impl Iterator<Item = &AccessibleNode> {
+        let mut stack: Vec<&AccessibleNode> = self.0.iter().rev().collect();
+        core::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    /// All the nodes having the role
+    pub fn query_all_by_role(&self, role: &str) -> Vec<&AccessibleNode> {
+        self.iter().filter(|node| node.role == role).collect()
+    }
+
+    /// The node having the role and name, or `None` if there is none
+    ///
+    /// # Panics
+    ///
+    /// If there are more than one matching node
+    pub fn query_by_role(&self, role: &str, name: &str) -> Option<&AccessibleNode> {
+        let mut matches = self
+            .iter()
+            .filter(|node| node.role == role && node.name == name);
+        let node = matches.next()?;
+        assert!(
+            matches.next().is_none(),
+            "found multiple nodes with role {role:?} and name {name:?} in:\n{self}"
+        );
+        Some(node)
+    }
+
+    /// The node having the role and name
+    ///
+    /// # Panics
+    ///
+    /// If there is no matching node, or if there are more than one
+    pub fn get_by_role(&self, role: &str, name: &str) -> &AccessibleNode {
+        match self.query_by_role(role, name) {
+            Some(node) => node,
+            None => panic!("found no node with role {role:?} and name {name:?} in:\n{self}"),
+        }
+    }
+}
+
+impl Display for AccessibilityTree {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fn write_node(
+            f: &mut core::fmt::Formatter<'_>,
+            node: &AccessibleNode,
+            depth: usize,
+            first: &mut bool,
+        ) -> core::fmt::Result {
+            if !*first {
+                writeln!(f)?;
+            }
+            *first = false;
+            write!(f, "{:indent$}{}", "", node.role, indent = depth * 2)?;
+            if !node.name.is_empty() {
+                write!(f, " {:?}", node.name)?;
+            }
+            for child in &node.children {
+                write_node(f, child, depth + 1, first)?;
+            }
+            Ok(())
+        }
+        let mut first = true;
+        for node in &self.0 {
+            write_node(f, node, 0, &mut first)?;
+        }
+        Ok(())
+    }
+}
+
+/// Roles whose name can be computed from the content
+const NAME_FROM_CONTENT: &[&str] = &[
+    "button",
+    "cell",
+    "checkbox",
+    "columnheader",
+    "gridcell",
+    "heading",
+    "link",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "option",
+    "radio",
+    "row",
+    "rowheader",
+    "switch",
+    "tab",
+    "tooltip",
+    "treeitem",
+];
+
+/// Lookup tables used to compute accessible names
+pub(super) struct Context<'a> {
+    ids: Vec<(&'a str, &'a Node<'a>)>,
+    labels: Vec<(&'a str, &'a Node<'a>)>,
+}
+
+impl<'a> Context<'a> {
+    pub(super) fn new(nodes: &'a [Node<'a>]) -> Self {
+        let mut context = Self {
+            ids: Vec::new(),
+            labels: Vec::new(),
+        };
+        visit(nodes, "", &mut |_, node| {
+            if let Some(id) = node.attribute("id") {
+                context.ids.push((id, node));
+            }
+            if let (Some("label"), Some(target)) = (node.tag(), node.attribute("for")) {
+                context.labels.push((target, node));
+            }
+        });
+        context
+    }
+
+    /// Returns `true` if a `label` element refers to this node with its `for` attribute
+    pub(super) fn has_label(&self, node: &Node<'_>) -> bool {
+        node.attribute("id").map_or(false, |id| {
+            self.labels.iter().any(|(target, _)| *target == id)
+        })
+    }
+
+    fn build(
+        &self,
+        output: &mut Vec<AccessibleNode>,
+        nodes: &'a [Node<'a>],
+        parent_path: &str,
+        label: Option<&'a Node<'a>>,
+        in_sectioning: bool,
+    ) {
+        for (path, node) in paths(parent_path, nodes).iter().zip(nodes) {
+            let tag = match node.tag() {
+                Some(tag) => tag,
+                None => continue,
+            };
+            if is_hidden(node)
+                || matches!(
+                    tag,
+                    "head"
+                        | "link"
+                        | "meta"
+                        | "noscript"
+                        | "script"
+                        | "style"
+                        | "template"
+                        | "title"
+                )
+            {
+                continue;
+            }
+            let mut children = Vec::new();
+            if let Node::Parent {
+                children: nodes, ..
+            } = node
+            {
+                let label = if tag == "label" { Some(node) } else { label };
+                let in_sectioning = in_sectioning
+                    || matches!(tag, "article" | "aside" | "main" | "nav" | "section");
+                self.build(&mut children, nodes, path, label, in_sectioning);
+            }
+            let role = explicit_role(node).or_else(|| implicit_role(node, in_sectioning));
+            let role = match role {
+                Some(role) if !matches!(role, "none" | "presentation" | "generic") => role,
+                _ => {
+                    output.extend(children);
+                    continue;
+                }
+            };
+            let name = self.name(node, role, label);
+            if role == "region" && name.is_empty() {
+                output.extend(children);
+                continue;
+            }
+            output.push(AccessibleNode {
+                role: role.into(),
+                name,
+                path: path.clone(),
+                children,
+            });
+        }
+    }
+
+    /// Accessible name of the node
+    ///
+    /// `label` is the nearest `label` ancestor, if any
+    fn name(&self, node: &'a Node<'a>, role: &str, label: Option<&'a Node<'a>>) -> String {
+        if let Some(ids) = node.attribute("aria-labelledby") {
+            let mut name = String::new();
+            for referenced in ids.split_whitespace().filter_map(|id| self.by_id(id)) {
+                name.push(' ');
+                name.push_str(&self.text(referenced));
+            }
+            let name = normalize_whitespace(&name);
+            if !name.is_empty() {
+                return name;
+            }
+        }
+        let name = normalize_whitespace(node.attribute("aria-label").unwrap_or_default());
+        if !name.is_empty() {
+            return name;
+        }
+        let name = normalize_whitespace(&self.native_name(node, label));
+        if !name.is_empty() {
+            return name;
+        }
+        if NAME_FROM_CONTENT.contains(&role) {
+            let name = normalize_whitespace(&self.text(node));
+            if !name.is_empty() {
+                return name;
+            }
+        }
+        normalize_whitespace(node.attribute("title").unwrap_or_default())
+    }
+
+    /// Name given by the host language (labels, `alt`, `legend`, etc.)
+    fn native_name(&self, node: &'a Node<'a>, label: Option<&'a Node<'a>>) -> String {
+        let tag = node.tag().unwrap_or_default();
+        let input_type = node
+            .attribute("type")
+            .map(str::to_ascii_lowercase)
+            .unwrap_or_default();
+        match tag {
+            "input" if input_type == "image" => node.attribute("alt").unwrap_or_default().into(),
+            "input" if matches!(input_type.as_str(), "button" | "submit" | "reset") => {
+                match node.attribute("value") {
+                    Some(value) => value.into(),
+                    None if input_type == "submit" => "Submit".into(),
+                    None if input_type == "reset" => "Reset".into(),
+                    None => String::new(),
+                }
+            }
+            "input" | "select" | "textarea" | "meter" | "progress" | "output" => {
+                let mut name = String::new();
+                let for_labels = node.attribute("id").into_iter().flat_map(|id| {
+                    self.labels
+                        .iter()
+                        .filter(move |(target, _)| *target == id)
+                        .map(|(_, label)| *label)
+                });
+                for label in label.into_iter().chain(for_labels) {
+                    name.push(' ');
+                    name.push_str(&self.text(label));
+                }
+                name
+            }
+            "img" | "area" => node.attribute("alt").unwrap_or_default().into(),
+            "fieldset" => self.child_text(node, "legend"),
+            "figure" => self.child_text(node, "figcaption"),
+            "table" => self.child_text(node, "caption"),
+            _ => String::new(),
+        }
+    }
+
+    fn child_text(&self, node: &'a Node<'a>, tag: &str) -> String {
+        match node {
+            Node::Parent { children, .. } => children
+                .iter()
+                .find(|child| child.tag() == Some(tag))
+                .map(|child| self.text(child))
+                .unwrap_or_default(),
+            _ => String::new(),
+        }
+    }
+
+    /// Text content of the node, as perceived by users
+    fn text(&self, node: &'a Node<'a>) -> String {
+        match node {
+            Node::Text(text) => text.to_string(),
+            Node::Raw(_) | Node::Script(_) => String::new(),
+            _ if is_hidden(node) => String::new(),
+            _ if node
+                .attribute("aria-label")
+                .map_or(false, |l| !l.trim().is_empty()) =>
+            {
+                let mut text = String::from(" ");
+                text.push_str(node.attribute("aria-label").unwrap_or_default());
+                text.push(' ');
+                text
+            }
+            Node::Void { tag: "img", .. } => {
+                let mut text = String::from(" ");
+                text.push_str(node.attribute("alt").unwrap_or_default());
+                text.push(' ');
+                text
+            }
+            Node::Void { .. } => String::new(),
+            Node::Parent {
+                tag: "script" | "style" | "template",
+                ..
+            } => String::new(),
+            Node::Parent { children, .. } => {
+                children.iter().map(|child| self.text(child)).collect()
+            }
+        }
+    }
+
+    fn by_id(&self, id: &str) -> Option<&'a Node<'a>> {
+        self.ids
+            .iter()
+            .find(|(node_id, _)| *node_id == id)
+            .map(|(_, node)| *node)
+    }
+}
+
+fn is_hidden(node: &Node<'_>) -> bool {
+    node.attribute("hidden").is_some()
+        || node
+            .attribute("aria-hidden")
+            .map_or(false, |hidden| hidden.eq_ignore_ascii_case("true"))
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    let mut normalized = String::new();
+    for word in text.split_whitespace() {
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        normalized.push_str(word);
+    }
+    normalized
+}
+
+fn explicit_role<'a>(node: &'a Node<'_>) -> Option<&'a str> {
+    node.attribute("role")?.split_whitespace().next()
+}
+
+fn implicit_role(node: &Node<'_>, in_sectioning: bool) -> Option<&'static str> {
+    let tag = node.tag()?;
+    Some(match tag {
+        "a" | "area" if node.attribute("href").is_some() => "link",
+        "article" => "article",
+        "aside" => "complementary",
+        "blockquote" => "blockquote",
+        "button" => "button",
+        "caption" => "caption",
+        "code" => "code",
+        "datalist" => "listbox",
+        "dd" => "definition",
+        "del" => "deletion",
+        "details" | "fieldset" | "hgroup" | "optgroup" => "group",
+        "dfn" | "dt" => "term",
+        "dialog" => "dialog",
+        "em" => "emphasis",
+        "figure" => "figure",
+        "footer" if !in_sectioning => "contentinfo",
+        "form" => "form",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+        "header" if !in_sectioning => "banner",
+        "hr" => "separator",
+        "html" => "document",
+        "img" if node.attribute("alt") == Some("") => "presentation",
+        "img" => "img",
+        "input" => return input_role(node),
+        "ins" => "insertion",
+        "li" => "listitem",
+        "main" => "main",
+        "math" => "math",
+        "menu" | "ol" | "ul" => "list",
+        "meter" => "meter",
+        "nav" => "navigation",
+        "option" => "option",
+        "output" => "status",
+        "p" => "paragraph",
+        "progress" => "progressbar",
+        "search" => "search",
+        "section" => "region",
+        "select"
+            if node.attribute("multiple").is_some()
+                || node
+                    .attribute("size")
+                    .and_then(|size| size.parse::<u32>().ok())
+                    .map_or(false, |size| size > 1) =>
+        {
+            "listbox"
+        }
+        "select" => "combobox",
+        "strong" => "strong",
+        "sub" => "subscript",
+        "sup" => "superscript",
+        "table" => "table",
+        "tbody" | "tfoot" | "thead" => "rowgroup",
+        "td" => "cell",
+        "textarea" => "textbox",
+        "th" if node.attribute("scope") == Some("row") => "rowheader",
+        "th" => "columnheader",
+        "time" => "time",
+        "tr" => "row",
+        _ => return None,
+    })
+}
+
+fn input_role(node: &Node<'_>) -> Option<&'static str> {
+    let input_type = node
+        .attribute("type")
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    let has_list = node.attribute("list").is_some();
+    Some(match input_type.as_str() {
+        "hidden" => return None,
+        "button" | "image" | "reset" | "submit" => "button",
+        "checkbox" => "checkbox",
+        "radio" => "radio",
+        "range" => "slider",
+        "number" => "spinbutton",
+        "search" if !has_list => "searchbox",
+        "" | "email" | "search" | "tel" | "text" | "url" if has_list => "combobox",
+        "" | "email" | "tel" | "text" | "url" => "textbox",
+        _ => return None,
+    })
+}
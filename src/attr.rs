@@ -3,9 +3,28 @@
 //! Note that you may create your own attribute by using [`Attribute::new`] or [`Attribute::new_flag`]
 //! Or by leveraging on of the `From` implementation on [`Attribute`]
 
-use alloc::{borrow::Cow, string::String};
-
-use crate::Attribute;
+pub mod aria;
+mod autocomplete;
+mod class_list;
+pub mod css;
+mod datetime;
+mod href;
+#[cfg(feature = "tailwind")]
+pub mod tailwind;
+
+use alloc::{
+    borrow::Cow,
+    fmt::{Display, Write},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{Attribute, Document, Element, Number};
+
+pub use autocomplete::{Autocomplete, ContactField, Field, InvalidSectionName};
+pub use class_list::{ClassEntry, ClassList};
+pub use datetime::HtmlDateTime;
+pub use href::Href;
 
 impl<T: Into<Cow<'static, str>>> From<(&'static str, T)> for Attribute {
     fn from((key, value): (&'static str, T)) -> Self {
@@ -62,11 +81,281 @@ pub fn class<'a>(classes: impl IntoIterator<Item = &'a str>) -> Attribute {
     Attribute::new("class", values)
 }
 
+/// `class` attribute resolving conflicting Tailwind utilities (see [`tailwind`])
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::attr::class_tw;
+///
+/// assert_eq!(
+///   class_tw(["p-2 text-sm", "p-4"]).to_string(),
+///   r#"class="text-sm p-4""#,
+/// );
+/// ```
+#[cfg(feature = "tailwind")]
+pub fn class_tw<'a>(classes: impl IntoIterator<Item = &'a str>) -> Attribute {
+    Attribute::new("class", tailwind::merge(classes))
+}
+
+/// `class` attribute from a [`ClassList`] (see also the [`classes!`](crate::classes) macro)
+pub fn class_list(classes: ClassList) -> Attribute {
+    Attribute::new("class", classes.into_inner())
+}
+
 /// `lang` attribute (usually on `html` element)
 pub fn lang(lang: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("lang", lang)
 }
 
+/// `title` attribute
+pub fn title(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("title", value)
+}
+
+/// `tabindex` attribute
+pub fn tabindex(value: i32) -> Attribute {
+    Attribute::new_int("tabindex", value)
+}
+
+/// `hidden` attribute
+pub fn hidden() -> Attribute {
+    Attribute::new_flag("hidden")
+}
+
+/// `hidden="until-found"`
+///
+/// The element is hidden, but its content can be found by the search of the browser, and revealed by fragment navigation
+pub fn hidden_until_found() -> Attribute {
+    Attribute::new("hidden", "until-found")
+}
+
+/// Text direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    /// `ltr` (left to right)
+    Ltr,
+    /// `rtl` (right to left)
+    Rtl,
+    /// `auto` (determined from the content)
+    Auto,
+}
+
+/// `dir` attribute
+pub fn dir(dir: Dir) -> Attribute {
+    Attribute::new(
+        "dir",
+        match dir {
+            Dir::Ltr => "ltr",
+            Dir::Rtl => "rtl",
+            Dir::Auto => "auto",
+        },
+    )
+}
+
+/// `translate` attribute (rendered as `yes` or `no`)
+pub fn translate(value: bool) -> Attribute {
+    Attribute::new("translate", if value { "yes" } else { "no" })
+}
+
+/// Value of the `contenteditable` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentEditable {
+    /// `true`
+    True,
+    /// `false`
+    False,
+    /// `plaintext-only`
+    PlaintextOnly,
+}
+
+/// `contenteditable` attribute
+pub fn contenteditable(value: ContentEditable) -> Attribute {
+    Attribute::new(
+        "contenteditable",
+        match value {
+            ContentEditable::True => "true",
+            ContentEditable::False => "false",
+            ContentEditable::PlaintextOnly => "plaintext-only",
+        },
+    )
+}
+
+/// `draggable` attribute (rendered as `true` or `false`)
+pub fn draggable(value: bool) -> Attribute {
+    Attribute::new("draggable", if value { "true" } else { "false" })
+}
+
+/// `spellcheck` attribute (rendered as `true` or `false`)
+pub fn spellcheck(value: bool) -> Attribute {
+    Attribute::new("spellcheck", if value { "true" } else { "false" })
+}
+
+/// `inert` attribute
+pub fn inert() -> Attribute {
+    Attribute::new_flag("inert")
+}
+
+/// Value of the `popover` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Popover {
+    /// `auto` (can be light dismissed, and closes other `auto` popovers)
+    Auto,
+    /// `manual`
+    Manual,
+    /// `hint`
+    Hint,
+}
+
+/// `popover` attribute
+pub fn popover(value: Popover) -> Attribute {
+    Attribute::new(
+        "popover",
+        match value {
+            Popover::Auto => "auto",
+            Popover::Manual => "manual",
+            Popover::Hint => "hint",
+        },
+    )
+}
+
+/// `popovertarget` attribute (id of the popover element controlled by a button)
+pub fn popovertarget(id: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("popovertarget", id)
+}
+
+/// Value of the `popovertargetaction` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PopoverTargetAction {
+    /// `toggle`
+    Toggle,
+    /// `show`
+    Show,
+    /// `hide`
+    Hide,
+}
+
+/// `popovertargetaction` attribute
+pub fn popovertargetaction(value: PopoverTargetAction) -> Attribute {
+    Attribute::new(
+        "popovertargetaction",
+        match value {
+            PopoverTargetAction::Toggle => "toggle",
+            PopoverTargetAction::Show => "show",
+            PopoverTargetAction::Hide => "hide",
+        },
+    )
+}
+
+/// `accesskey` attribute
+pub fn accesskey(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("accesskey", value)
+}
+
+/// Value of the `enterkeyhint` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnterKeyHint {
+    /// `enter`
+    Enter,
+    /// `done`
+    Done,
+    /// `go`
+    Go,
+    /// `next`
+    Next,
+    /// `previous`
+    Previous,
+    /// `search`
+    Search,
+    /// `send`
+    Send,
+}
+
+/// `enterkeyhint` attribute
+pub fn enterkeyhint(value: EnterKeyHint) -> Attribute {
+    Attribute::new(
+        "enterkeyhint",
+        match value {
+            EnterKeyHint::Enter => "enter",
+            EnterKeyHint::Done => "done",
+            EnterKeyHint::Go => "go",
+            EnterKeyHint::Next => "next",
+            EnterKeyHint::Previous => "previous",
+            EnterKeyHint::Search => "search",
+            EnterKeyHint::Send => "send",
+        },
+    )
+}
+
+/// Value of the `inputmode` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputMode {
+    /// `none` (no virtual keyboard)
+    None,
+    /// `text`
+    Text,
+    /// `decimal`
+    Decimal,
+    /// `numeric`
+    Numeric,
+    /// `tel`
+    Tel,
+    /// `search`
+    Search,
+    /// `email`
+    Email,
+    /// `url`
+    Url,
+}
+
+/// `inputmode` attribute
+pub fn inputmode(value: InputMode) -> Attribute {
+    Attribute::new(
+        "inputmode",
+        match value {
+            InputMode::None => "none",
+            InputMode::Text => "text",
+            InputMode::Decimal => "decimal",
+            InputMode::Numeric => "numeric",
+            InputMode::Tel => "tel",
+            InputMode::Search => "search",
+            InputMode::Email => "email",
+            InputMode::Url => "url",
+        },
+    )
+}
+
+/// Value of the `autocapitalize` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Autocapitalize {
+    /// `none`
+    None,
+    /// `sentences`
+    Sentences,
+    /// `words`
+    Words,
+    /// `characters`
+    Characters,
+}
+
+/// `autocapitalize` attribute
+pub fn autocapitalize(value: Autocapitalize) -> Attribute {
+    Attribute::new(
+        "autocapitalize",
+        match value {
+            Autocapitalize::None => "none",
+            Autocapitalize::Sentences => "sentences",
+            Autocapitalize::Words => "words",
+            Autocapitalize::Characters => "characters",
+        },
+    )
+}
+
+/// `nonce` attribute
+pub fn nonce(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("nonce", value)
+}
+
 /// Represent an anchor target
 #[derive(Debug, Clone)]
 pub enum AnchorTarget {
@@ -82,18 +371,21 @@ pub enum AnchorTarget {
     Frame(Cow<'static, str>),
 }
 
-/// `target` attribute for `<a>`
-pub fn target(target: AnchorTarget) -> Attribute {
-    Attribute::new(
-        "target",
-        match target {
+impl AnchorTarget {
+    fn into_value(self) -> Cow<'static, str> {
+        match self {
             AnchorTarget::Blank => "_blank".into(),
             AnchorTarget::Self_ => "_self".into(),
             AnchorTarget::Parent => "_parent".into(),
             AnchorTarget::Top => "_top".into(),
             AnchorTarget::Frame(name) => name,
-        },
-    )
+        }
+    }
+}
+
+/// `target` attribute for `<a>`
+pub fn target(target: AnchorTarget) -> Attribute {
+    Attribute::new("target", target.into_value())
 }
 
 /// Alias for `target(Target::Blank)`
@@ -102,6 +394,8 @@ pub fn target_blank() -> Attribute {
 }
 
 /// `href` attribute
+///
+/// It also accepts an [`Href`], to build URLs with percent-encoded query parameters
 pub fn href(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("href", value)
 }
@@ -111,7 +405,131 @@ pub fn rel(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("rel", value)
 }
 
+/// Link type, for the `rel` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkRel {
+    /// `alternate`
+    Alternate,
+    /// `author`
+    Author,
+    /// `bookmark`
+    Bookmark,
+    /// `canonical`
+    Canonical,
+    /// `dns-prefetch`
+    DnsPrefetch,
+    /// `external`
+    External,
+    /// `help`
+    Help,
+    /// `icon`
+    Icon,
+    /// `license`
+    License,
+    /// `manifest`
+    Manifest,
+    /// `me`
+    Me,
+    /// `modulepreload`
+    ModulePreload,
+    /// `next`
+    Next,
+    /// `nofollow`
+    NoFollow,
+    /// `noopener`
+    NoOpener,
+    /// `noreferrer`
+    NoReferrer,
+    /// `opener`
+    Opener,
+    /// `pingback`
+    Pingback,
+    /// `preconnect`
+    Preconnect,
+    /// `prefetch`
+    Prefetch,
+    /// `preload`
+    Preload,
+    /// `prev`
+    Prev,
+    /// `privacy-policy`
+    PrivacyPolicy,
+    /// `search`
+    Search,
+    /// `stylesheet`
+    Stylesheet,
+    /// `tag`
+    Tag,
+    /// `terms-of-service`
+    TermsOfService,
+}
+
+impl LinkRel {
+    fn as_str(self) -> &'static str {
+        match self {
+            LinkRel::Alternate => "alternate",
+            LinkRel::Author => "author",
+            LinkRel::Bookmark => "bookmark",
+            LinkRel::Canonical => "canonical",
+            LinkRel::DnsPrefetch => "dns-prefetch",
+            LinkRel::External => "external",
+            LinkRel::Help => "help",
+            LinkRel::Icon => "icon",
+            LinkRel::License => "license",
+            LinkRel::Manifest => "manifest",
+            LinkRel::Me => "me",
+            LinkRel::ModulePreload => "modulepreload",
+            LinkRel::Next => "next",
+            LinkRel::NoFollow => "nofollow",
+            LinkRel::NoOpener => "noopener",
+            LinkRel::NoReferrer => "noreferrer",
+            LinkRel::Opener => "opener",
+            LinkRel::Pingback => "pingback",
+            LinkRel::Preconnect => "preconnect",
+            LinkRel::Prefetch => "prefetch",
+            LinkRel::Preload => "preload",
+            LinkRel::Prev => "prev",
+            LinkRel::PrivacyPolicy => "privacy-policy",
+            LinkRel::Search => "search",
+            LinkRel::Stylesheet => "stylesheet",
+            LinkRel::Tag => "tag",
+            LinkRel::TermsOfService => "terms-of-service",
+        }
+    }
+}
+
+/// `rel` attribute, with a set of link types
+///
+/// Duplicated link types are rendered once.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::attr::{self, LinkRel};
+/// assert_eq!(
+///     attr::link_rel([LinkRel::NoOpener, LinkRel::NoReferrer, LinkRel::NoOpener]).to_string(),
+///     r#"rel="noopener noreferrer""#,
+/// );
+/// ```
+pub fn link_rel(rels: impl IntoIterator<Item = LinkRel>) -> Attribute {
+    let mut value = String::new();
+    let mut included = Vec::new();
+    for rel in rels {
+        if included.contains(&rel) {
+            continue;
+        }
+        if !value.is_empty() {
+            value.push(' ');
+        }
+        value.push_str(rel.as_str());
+        included.push(rel);
+    }
+    Attribute::new("rel", value)
+}
+
 /// `src` attribute
+///
+/// It also accepts an [`Href`], to build URLs with percent-encoded query parameters
 pub fn src(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("src", value)
 }
@@ -141,7 +559,763 @@ pub fn height_int(value: i32) -> Attribute {
     Attribute::new_int("height", value)
 }
 
+/// `controls` attribute (for `<audio>` and `<video>`)
+pub fn controls() -> Attribute {
+    Attribute::new_flag("controls")
+}
+
+/// `autoplay` attribute (for `<audio>` and `<video>`)
+pub fn autoplay() -> Attribute {
+    Attribute::new_flag("autoplay")
+}
+
+/// `muted` attribute (for `<audio>` and `<video>`)
+pub fn muted() -> Attribute {
+    Attribute::new_flag("muted")
+}
+
+/// `loop` attribute (for `<audio>` and `<video>`)
+pub fn loop_() -> Attribute {
+    Attribute::new_flag("loop")
+}
+
+/// `playsinline` attribute (for `<video>`)
+pub fn playsinline() -> Attribute {
+    Attribute::new_flag("playsinline")
+}
+
+/// `poster` attribute (for `<video>`)
+pub fn poster(url: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("poster", url)
+}
+
+/// Value of the `preload` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Preload {
+    /// `none`
+    None,
+    /// `metadata`
+    Metadata,
+    /// `auto`
+    Auto,
+}
+
+/// `preload` attribute (for `<audio>` and `<video>`)
+pub fn preload(value: Preload) -> Attribute {
+    Attribute::new(
+        "preload",
+        match value {
+            Preload::None => "none",
+            Preload::Metadata => "metadata",
+            Preload::Auto => "auto",
+        },
+    )
+}
+
+/// Value of the `loading` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Loading {
+    /// `lazy`
+    Lazy,
+    /// `eager`
+    Eager,
+}
+
+/// `loading` attribute (for `<img>` and `<iframe>`)
+pub fn loading(value: Loading) -> Attribute {
+    Attribute::new(
+        "loading",
+        match value {
+            Loading::Lazy => "lazy",
+            Loading::Eager => "eager",
+        },
+    )
+}
+
+/// Value of the `decoding` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Decoding {
+    /// `sync`
+    Sync,
+    /// `async`
+    Async,
+    /// `auto`
+    Auto,
+}
+
+/// `decoding` attribute (for `<img>`)
+pub fn decoding(value: Decoding) -> Attribute {
+    Attribute::new(
+        "decoding",
+        match value {
+            Decoding::Sync => "sync",
+            Decoding::Async => "async",
+            Decoding::Auto => "auto",
+        },
+    )
+}
+
+/// Value of the `fetchpriority` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FetchPriority {
+    /// `high`
+    High,
+    /// `low`
+    Low,
+    /// `auto`
+    Auto,
+}
+
+/// `fetchpriority` attribute
+pub fn fetchpriority(value: FetchPriority) -> Attribute {
+    Attribute::new(
+        "fetchpriority",
+        match value {
+            FetchPriority::High => "high",
+            FetchPriority::Low => "low",
+            FetchPriority::Auto => "auto",
+        },
+    )
+}
+
+/// Image candidates of the `srcset` attribute
+///
+/// Whitespace in URLs is percent-encoded, so that it cannot be confused with the descriptors.
+///
+/// Note that a `srcset` should either use width descriptors (with [`sizes`]) or pixel density descriptors,
+/// but not both.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr::{self, Sizes, Srcset}, elt};
+/// let element = elt::img([
+///     attr::src("/photo-800.jpg"),
+///     attr::srcset(Srcset::new().width("/photo-400.jpg", 400).width("/photo-800.jpg", 800)),
+///     attr::sizes(Sizes::new("100vw").media("(min-width: 800px)", "50vw")),
+/// ]);
+///
+/// assert_eq!(
+///     element.to_string(),
+///     r#"<img src="/photo-800.jpg" srcset="/photo-400.jpg 400w, /photo-800.jpg 800w" sizes="(min-width: 800px) 50vw, 100vw">"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Srcset(String);
+
+impl Srcset {
+    /// Create an empty set of candidates
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a candidate with a width descriptor (e.g. `/photo.jpg 800w`)
+    pub fn width(self, url: &str, width: u32) -> Self {
+        self.push(url, format_args!("{width}w"))
+    }
+
+    /// Add a candidate with a pixel density descriptor (e.g. `/photo.jpg 2x`)
+    ///
+    /// The density must be a strictly positive finite number, which is checked in debug builds
+    pub fn density(self, url: &str, density: f32) -> Self {
+        debug_assert!(
+            density.is_finite() && density > 0.0,
+            "invalid pixel density: {density}"
+        );
+        self.push(url, format_args!("{density}x"))
+    }
+
+    fn push(mut self, url: &str, descriptor: core::fmt::Arguments<'_>) -> Self {
+        if !self.0.is_empty() {
+            self.0.push_str(", ");
+        }
+        for (i, c) in url.char_indices() {
+            match c {
+                ' ' => self.0.push_str("%20"),
+                '\t' => self.0.push_str("%09"),
+                '\n' => self.0.push_str("%0A"),
+                '\x0C' => self.0.push_str("%0C"),
+                '\r' => self.0.push_str("%0D"),
+                ',' if i == 0 || i == url.len() - 1 => self.0.push_str("%2C"),
+                c => self.0.push(c),
+            }
+        }
+        let _ = write!(self.0, " {descriptor}");
+        self
+    }
+}
+
+/// `srcset` attribute (for `<img>` and `<source>`)
+///
+/// See [`Srcset`]
+pub fn srcset(value: Srcset) -> Attribute {
+    Attribute::new("srcset", value.0)
+}
+
+/// Layout widths of an image, for the `sizes` attribute
+///
+/// The first media condition that matches determines the width of the image,
+/// or the default width if none matches.
+///
+/// See [`Srcset`] for an example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sizes {
+    conditions: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    default: Cow<'static, str>,
+}
+
+impl Sizes {
+    /// Create sizes with a default width (e.g. `100vw`)
+    pub fn new(default: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            conditions: Vec::new(),
+            default: default.into(),
+        }
+    }
+
+    /// Add a width used when the media condition matches (e.g. `(min-width: 800px)` and `50vw`)
+    pub fn media(
+        mut self,
+        condition: impl Into<Cow<'static, str>>,
+        width: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.conditions.push((condition.into(), width.into()));
+        self
+    }
+}
+
+impl Display for Sizes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (condition, width) in &self.conditions {
+            write!(f, "{condition} {width}, ")?;
+        }
+        write!(f, "{}", self.default)
+    }
+}
+
+/// `sizes` attribute (for `<img>` and `<source>`)
+///
+/// See [`Sizes`]
+pub fn sizes(value: Sizes) -> Attribute {
+    Attribute::new("sizes", value.to_string())
+}
+
+/// `media` attribute (media query, for `<source>`, `<link>` and `<style>`)
+pub fn media(query: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("media", query)
+}
+
+/// Kind of text track
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrackKind {
+    /// `subtitles`
+    Subtitles,
+    /// `captions`
+    Captions,
+    /// `descriptions`
+    Descriptions,
+    /// `chapters`
+    Chapters,
+    /// `metadata`
+    Metadata,
+}
+
+/// `kind` attribute (for `<track>`)
+pub fn kind(value: TrackKind) -> Attribute {
+    Attribute::new(
+        "kind",
+        match value {
+            TrackKind::Subtitles => "subtitles",
+            TrackKind::Captions => "captions",
+            TrackKind::Descriptions => "descriptions",
+            TrackKind::Chapters => "chapters",
+            TrackKind::Metadata => "metadata",
+        },
+    )
+}
+
+/// `srclang` attribute (for `<track>`)
+pub fn srclang(lang: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("srclang", lang)
+}
+
+/// `label` attribute (for `<track>`, `<option>` and `<optgroup>`)
+pub fn label(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("label", value)
+}
+
+/// `default` attribute (for `<track>`)
+pub fn default() -> Attribute {
+    Attribute::new_flag("default")
+}
+
+/// Shape of an `<area>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    /// `default` (the whole image)
+    Default,
+    /// `rect`
+    Rect,
+    /// `circle`
+    Circle,
+    /// `poly`
+    Poly,
+}
+
+/// `shape` attribute (for `<area>`)
+pub fn shape(value: Shape) -> Attribute {
+    Attribute::new(
+        "shape",
+        match value {
+            Shape::Default => "default",
+            Shape::Rect => "rect",
+            Shape::Circle => "circle",
+            Shape::Poly => "poly",
+        },
+    )
+}
+
+/// `coords` attribute (for `<area>`)
+pub fn coords(coords: impl IntoIterator<Item = i32>) -> Attribute {
+    let mut value = String::new();
+    for (i, coord) in coords.into_iter().enumerate() {
+        if i > 0 {
+            value.push(',');
+        }
+        let _ = write!(value, "{coord}");
+    }
+    Attribute::new("coords", value)
+}
+
+/// `usemap` attribute (for `<img>`), referencing a `<map>` by name
+pub fn usemap(map_name: impl Into<Cow<'static, str>>) -> Attribute {
+    let mut value = String::from("#");
+    value.push_str(&map_name.into());
+    Attribute::new("usemap", value)
+}
+
+/// Restrictions lifted for the content of a sandboxed `<iframe>`
+///
+/// All restrictions apply by default, and each method lifts one of them.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr::{self, Sandbox}, elt};
+/// let element = elt::iframe([
+///     attr::src("https://example.com/widget"),
+///     attr::sandbox(Sandbox::new().allow_scripts().allow_forms()),
+/// ]);
+///
+/// assert_eq!(
+///     element.to_string(),
+///     r#"<iframe src="https://example.com/widget" sandbox="allow-forms allow-scripts"></iframe>"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Sandbox(u16);
+
+const SANDBOX_TOKENS: [&str; 14] = [
+    "allow-downloads",
+    "allow-forms",
+    "allow-modals",
+    "allow-orientation-lock",
+    "allow-pointer-lock",
+    "allow-popups",
+    "allow-popups-to-escape-sandbox",
+    "allow-presentation",
+    "allow-same-origin",
+    "allow-scripts",
+    "allow-storage-access-by-user-activation",
+    "allow-top-navigation",
+    "allow-top-navigation-by-user-activation",
+    "allow-top-navigation-to-custom-protocols",
+];
+
+impl Sandbox {
+    /// All restrictions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn allow(self, token: usize) -> Self {
+        Self(self.0 | (1 << token))
+    }
+
+    /// `allow-downloads`
+    pub fn allow_downloads(self) -> Self {
+        self.allow(0)
+    }
+
+    /// `allow-forms`
+    pub fn allow_forms(self) -> Self {
+        self.allow(1)
+    }
+
+    /// `allow-modals`
+    pub fn allow_modals(self) -> Self {
+        self.allow(2)
+    }
+
+    /// `allow-orientation-lock`
+    pub fn allow_orientation_lock(self) -> Self {
+        self.allow(3)
+    }
+
+    /// `allow-pointer-lock`
+    pub fn allow_pointer_lock(self) -> Self {
+        self.allow(4)
+    }
+
+    /// `allow-popups`
+    pub fn allow_popups(self) -> Self {
+        self.allow(5)
+    }
+
+    /// `allow-popups-to-escape-sandbox`
+    pub fn allow_popups_to_escape_sandbox(self) -> Self {
+        self.allow(6)
+    }
+
+    /// `allow-presentation`
+    pub fn allow_presentation(self) -> Self {
+        self.allow(7)
+    }
+
+    /// `allow-same-origin`
+    ///
+    /// Note that combining it with [`Sandbox::allow_scripts`] allows the content to remove its own sandbox
+    /// if it has the same origin as the embedding page
+    pub fn allow_same_origin(self) -> Self {
+        self.allow(8)
+    }
+
+    /// `allow-scripts`
+    pub fn allow_scripts(self) -> Self {
+        self.allow(9)
+    }
+
+    /// `allow-storage-access-by-user-activation`
+    pub fn allow_storage_access_by_user_activation(self) -> Self {
+        self.allow(10)
+    }
+
+    /// `allow-top-navigation`
+    pub fn allow_top_navigation(self) -> Self {
+        self.allow(11)
+    }
+
+    /// `allow-top-navigation-by-user-activation`
+    pub fn allow_top_navigation_by_user_activation(self) -> Self {
+        self.allow(12)
+    }
+
+    /// `allow-top-navigation-to-custom-protocols`
+    pub fn allow_top_navigation_to_custom_protocols(self) -> Self {
+        self.allow(13)
+    }
+}
+
+impl Display for Sandbox {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let tokens = SANDBOX_TOKENS
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.0 & (1 << i) != 0);
+        for (n, (_, token)) in tokens.enumerate() {
+            if n > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(token)?;
+        }
+        Ok(())
+    }
+}
+
+/// `sandbox` attribute (for `<iframe>`)
+///
+/// See [`Sandbox`]
+pub fn sandbox(value: Sandbox) -> Attribute {
+    Attribute::new("sandbox", value.to_string())
+}
+
+/// Feature controlled by a permissions policy
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Feature {
+    /// `accelerometer`
+    Accelerometer,
+    /// `autoplay`
+    Autoplay,
+    /// `bluetooth`
+    Bluetooth,
+    /// `camera`
+    Camera,
+    /// `clipboard-read`
+    ClipboardRead,
+    /// `clipboard-write`
+    ClipboardWrite,
+    /// `display-capture`
+    DisplayCapture,
+    /// `encrypted-media`
+    EncryptedMedia,
+    /// `fullscreen`
+    Fullscreen,
+    /// `gamepad`
+    Gamepad,
+    /// `geolocation`
+    Geolocation,
+    /// `gyroscope`
+    Gyroscope,
+    /// `hid`
+    Hid,
+    /// `idle-detection`
+    IdleDetection,
+    /// `local-fonts`
+    LocalFonts,
+    /// `magnetometer`
+    Magnetometer,
+    /// `microphone`
+    Microphone,
+    /// `midi`
+    Midi,
+    /// `payment`
+    Payment,
+    /// `picture-in-picture`
+    PictureInPicture,
+    /// `publickey-credentials-get`
+    PublickeyCredentialsGet,
+    /// `screen-wake-lock`
+    ScreenWakeLock,
+    /// `serial`
+    Serial,
+    /// `storage-access`
+    StorageAccess,
+    /// `usb`
+    Usb,
+    /// `web-share`
+    WebShare,
+    /// `xr-spatial-tracking`
+    XrSpatialTracking,
+    /// Any other feature
+    Other(Cow<'static, str>),
+}
+
+impl Feature {
+    fn as_str(&self) -> &str {
+        match self {
+            Feature::Accelerometer => "accelerometer",
+            Feature::Autoplay => "autoplay",
+            Feature::Bluetooth => "bluetooth",
+            Feature::Camera => "camera",
+            Feature::ClipboardRead => "clipboard-read",
+            Feature::ClipboardWrite => "clipboard-write",
+            Feature::DisplayCapture => "display-capture",
+            Feature::EncryptedMedia => "encrypted-media",
+            Feature::Fullscreen => "fullscreen",
+            Feature::Gamepad => "gamepad",
+            Feature::Geolocation => "geolocation",
+            Feature::Gyroscope => "gyroscope",
+            Feature::Hid => "hid",
+            Feature::IdleDetection => "idle-detection",
+            Feature::LocalFonts => "local-fonts",
+            Feature::Magnetometer => "magnetometer",
+            Feature::Microphone => "microphone",
+            Feature::Midi => "midi",
+            Feature::Payment => "payment",
+            Feature::PictureInPicture => "picture-in-picture",
+            Feature::PublickeyCredentialsGet => "publickey-credentials-get",
+            Feature::ScreenWakeLock => "screen-wake-lock",
+            Feature::Serial => "serial",
+            Feature::StorageAccess => "storage-access",
+            Feature::Usb => "usb",
+            Feature::WebShare => "web-share",
+            Feature::XrSpatialTracking => "xr-spatial-tracking",
+            Feature::Other(name) => name,
+        }
+    }
+}
+
+/// Origin allowed to use a [`Feature`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// `'src'` (the origin of the `src` of the `<iframe>`)
+    Src,
+    /// `'self'` (the origin of the embedding page)
+    Self_,
+    /// `'none'`
+    None,
+    /// `*` (any origin)
+    Any,
+    /// An origin (e.g. `https://example.com`)
+    Url(Cow<'static, str>),
+}
+
+/// Permissions policy of an `<iframe>`, for the `allow` attribute
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr::{self, Allow, Feature, Origin}, elt};
+/// let element = elt::iframe([
+///     attr::src("https://example.com/player"),
+///     attr::allow(
+///         Allow::new()
+///             .feature(Feature::Fullscreen)
+///             .feature_for(Feature::Camera, [Origin::Self_, Origin::Url("https://example.com".into())]),
+///     ),
+/// ]);
+///
+/// assert_eq!(
+///     element.to_string(),
+///     r#"<iframe src="https://example.com/player" allow="fullscreen; camera 'self' https://example.com"></iframe>"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Allow(String);
+
+impl Allow {
+    /// Empty policy
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow the feature for the origin of the `<iframe>` content
+    pub fn feature(mut self, feature: Feature) -> Self {
+        self.push_feature(&feature);
+        self
+    }
+
+    /// Allow the feature for the origins
+    pub fn feature_for(
+        mut self,
+        feature: Feature,
+        origins: impl IntoIterator<Item = Origin>,
+    ) -> Self {
+        self.push_feature(&feature);
+        for origin in origins {
+            self.0.push(' ');
+            match origin {
+                Origin::Src => self.0.push_str("'src'"),
+                Origin::Self_ => self.0.push_str("'self'"),
+                Origin::None => self.0.push_str("'none'"),
+                Origin::Any => self.0.push('*'),
+                Origin::Url(url) => self.0.push_str(&url),
+            }
+        }
+        self
+    }
+
+    fn push_feature(&mut self, feature: &Feature) {
+        if !self.0.is_empty() {
+            self.0.push_str("; ");
+        }
+        self.0.push_str(feature.as_str());
+    }
+}
+
+/// `allow` attribute (for `<iframe>`)
+///
+/// See [`Allow`]
+pub fn allow(value: Allow) -> Attribute {
+    Attribute::new("allow", value.0)
+}
+
+/// Value of the `referrerpolicy` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReferrerPolicy {
+    /// `no-referrer`
+    NoReferrer,
+    /// `no-referrer-when-downgrade`
+    NoReferrerWhenDowngrade,
+    /// `origin`
+    Origin,
+    /// `origin-when-cross-origin`
+    OriginWhenCrossOrigin,
+    /// `same-origin`
+    SameOrigin,
+    /// `strict-origin`
+    StrictOrigin,
+    /// `strict-origin-when-cross-origin`
+    StrictOriginWhenCrossOrigin,
+    /// `unsafe-url`
+    UnsafeUrl,
+}
+
+/// `referrerpolicy` attribute
+pub fn referrerpolicy(value: ReferrerPolicy) -> Attribute {
+    Attribute::new(
+        "referrerpolicy",
+        match value {
+            ReferrerPolicy::NoReferrer => "no-referrer",
+            ReferrerPolicy::NoReferrerWhenDowngrade => "no-referrer-when-downgrade",
+            ReferrerPolicy::Origin => "origin",
+            ReferrerPolicy::OriginWhenCrossOrigin => "origin-when-cross-origin",
+            ReferrerPolicy::SameOrigin => "same-origin",
+            ReferrerPolicy::StrictOrigin => "strict-origin",
+            ReferrerPolicy::StrictOriginWhenCrossOrigin => "strict-origin-when-cross-origin",
+            ReferrerPolicy::UnsafeUrl => "unsafe-url",
+        },
+    )
+}
+
+/// HTML content of the `srcdoc` attribute
+///
+/// It can be created from a [`Document`] or an [`Element`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Srcdoc(String);
+
+impl From<&Document> for Srcdoc {
+    fn from(document: &Document) -> Self {
+        Self(document.to_string())
+    }
+}
+
+impl From<Document> for Srcdoc {
+    fn from(document: Document) -> Self {
+        Self::from(&document)
+    }
+}
+
+impl From<&Element> for Srcdoc {
+    fn from(element: &Element) -> Self {
+        Self(element.to_string())
+    }
+}
+
+impl From<Element> for Srcdoc {
+    fn from(element: Element) -> Self {
+        Self::from(&element)
+    }
+}
+
+/// `srcdoc` attribute (for `<iframe>`)
+///
+/// The content is rendered, then escaped as an attribute value.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr, elt};
+/// let element = elt::iframe([attr::srcdoc(elt::p([], ["Fish & \"Chips\"".into()]))]);
+///
+/// assert_eq!(
+///     element.to_string(),
+///     r#"<iframe srcdoc="&lt;p&gt;Fish &amp;amp; &quot;Chips&quot;&lt;/p&gt;"></iframe>"#,
+/// );
+/// ```
+pub fn srcdoc(content: impl Into<Srcdoc>) -> Attribute {
+    Attribute::new("srcdoc", content.into().0)
+}
+
+/// `data` attribute (URL of the resource, for `<object>`)
+pub fn data(url: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("data", url)
+}
+
 /// `style` attribute
+///
+/// Accepts a raw string, or a [`css::Style`] built from typed properties
 pub fn style(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("style", value)
 }
@@ -156,79 +1330,273 @@ pub fn rows(value: i32) -> Attribute {
     Attribute::new_int("rows", value)
 }
 
+/// `colspan` attribute (for `<td>` and `<th>`)
+///
+/// The value must be between 1 and 1000, which is checked in debug builds
+/// and by the [`AttributeChecker`](crate::validate::AttributeChecker)
+pub fn colspan(value: u16) -> Attribute {
+    debug_assert!((1..=1000).contains(&value), "invalid colspan: {value}");
+    Attribute::new_number("colspan", value)
+}
+
+/// `rowspan` attribute (for `<td>` and `<th>`)
+///
+/// The value must be at most 65534, which is checked in debug builds
+/// and by the [`AttributeChecker`](crate::validate::AttributeChecker).
+/// Zero means that the cell spans all the remaining rows of the row group.
+pub fn rowspan(value: u16) -> Attribute {
+    debug_assert!(value <= 65534, "invalid rowspan: {value}");
+    Attribute::new_number("rowspan", value)
+}
+
+/// `span` attribute (for `<col>` and `<colgroup>`)
+///
+/// The value must be between 1 and 1000, which is checked in debug builds
+/// and by the [`AttributeChecker`](crate::validate::AttributeChecker)
+pub fn span(value: u16) -> Attribute {
+    debug_assert!((1..=1000).contains(&value), "invalid span: {value}");
+    Attribute::new_number("span", value)
+}
+
+/// Cells covered by a header cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// `row`
+    Row,
+    /// `col`
+    Col,
+    /// `rowgroup`
+    RowGroup,
+    /// `colgroup`
+    ColGroup,
+}
+
+/// `scope` attribute (for `<th>`)
+pub fn scope(value: Scope) -> Attribute {
+    Attribute::new(
+        "scope",
+        match value {
+            Scope::Row => "row",
+            Scope::Col => "col",
+            Scope::RowGroup => "rowgroup",
+            Scope::ColGroup => "colgroup",
+        },
+    )
+}
+
+/// `headers` attribute (ids of the header cells of a `<td>` or `<th>`)
+pub fn headers(ids: impl Into<aria::IdRefs>) -> Attribute {
+    Attribute::new("headers", ids.into().into_inner())
+}
+
+/// `abbr` attribute (short label of a `<th>`)
+pub fn abbr(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("abbr", value)
+}
+
 /// `type` attribute
 pub fn type_(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("type", value)
 }
 
+/// Type of an `<input>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputType {
+    /// `hidden`
+    Hidden,
+    /// `text`
+    Text,
+    /// `search`
+    Search,
+    /// `tel`
+    Tel,
+    /// `url`
+    Url,
+    /// `email`
+    Email,
+    /// `password`
+    Password,
+    /// `date`
+    Date,
+    /// `month`
+    Month,
+    /// `week`
+    Week,
+    /// `time`
+    Time,
+    /// `datetime-local`
+    DatetimeLocal,
+    /// `number`
+    Number,
+    /// `range`
+    Range,
+    /// `color`
+    Color,
+    /// `checkbox`
+    Checkbox,
+    /// `radio`
+    Radio,
+    /// `file`
+    File,
+    /// `submit`
+    Submit,
+    /// `image`
+    Image,
+    /// `reset`
+    Reset,
+    /// `button`
+    Button,
+}
+
+/// `type` attribute of an `<input>`
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::attr::{self, InputType};
+/// let secret = true;
+/// let input_type = if secret { InputType::Password } else { InputType::Text };
+///
+/// assert_eq!(attr::input_type(input_type).to_string(), r#"type="password""#);
+/// ```
+pub fn input_type(value: InputType) -> Attribute {
+    Attribute::new(
+        "type",
+        match value {
+            InputType::Hidden => "hidden",
+            InputType::Text => "text",
+            InputType::Search => "search",
+            InputType::Tel => "tel",
+            InputType::Url => "url",
+            InputType::Email => "email",
+            InputType::Password => "password",
+            InputType::Date => "date",
+            InputType::Month => "month",
+            InputType::Week => "week",
+            InputType::Time => "time",
+            InputType::DatetimeLocal => "datetime-local",
+            InputType::Number => "number",
+            InputType::Range => "range",
+            InputType::Color => "color",
+            InputType::Checkbox => "checkbox",
+            InputType::Radio => "radio",
+            InputType::File => "file",
+            InputType::Submit => "submit",
+            InputType::Image => "image",
+            InputType::Reset => "reset",
+            InputType::Button => "button",
+        },
+    )
+}
+
 /// `type="text"` (text input)
 pub fn type_text() -> Attribute {
-    Attribute::new("type", "text")
+    input_type(InputType::Text)
 }
 
 /// `type="password"` (password input)
 pub fn type_password() -> Attribute {
-    Attribute::new("type", "password")
+    input_type(InputType::Password)
 }
 
 /// `type="number"` (number input)
 pub fn type_number() -> Attribute {
-    Attribute::new("type", "number")
+    input_type(InputType::Number)
 }
 
 /// `type="tel"` (phone number input)
 pub fn type_tel() -> Attribute {
-    Attribute::new("type", "tel")
+    input_type(InputType::Tel)
 }
 
 /// `type="file"` (file input)
 pub fn type_file() -> Attribute {
-    Attribute::new("type", "file")
+    input_type(InputType::File)
 }
 
 /// `type="checkbox"` (checkbox input)
 pub fn type_checkbox() -> Attribute {
-    Attribute::new("type", "checkbox")
+    input_type(InputType::Checkbox)
 }
 
 /// `type="radio"` (radio input)
 pub fn type_radio() -> Attribute {
-    Attribute::new("type", "radio")
+    input_type(InputType::Radio)
 }
 
 /// `type="range"` (range input)
 pub fn type_range() -> Attribute {
-    Attribute::new("type", "range")
+    input_type(InputType::Range)
 }
 
 /// `type="email"` (email input)
 pub fn type_email() -> Attribute {
-    Attribute::new("type", "email")
+    input_type(InputType::Email)
 }
 
 /// `type="date"` (date input)
 pub fn type_date() -> Attribute {
-    Attribute::new("type", "date")
+    input_type(InputType::Date)
 }
 
 /// `type="month"` (month input)
 pub fn type_month() -> Attribute {
-    Attribute::new("type", "month")
+    input_type(InputType::Month)
 }
 
 /// `type="hidden"` (hidden input)
 pub fn type_hidden() -> Attribute {
-    Attribute::new("type", "hidden")
+    input_type(InputType::Hidden)
 }
 
 /// `type="reset"` (reset button)
 pub fn type_reset() -> Attribute {
-    Attribute::new("type", "reset")
+    input_type(InputType::Reset)
 }
 
 /// `type="submit"` (reset button)
 pub fn type_submit() -> Attribute {
-    Attribute::new("type", "submit")
+    input_type(InputType::Submit)
+}
+
+/// `type="url"` (URL input)
+pub fn type_url() -> Attribute {
+    input_type(InputType::Url)
+}
+
+/// `type="search"` (search input)
+pub fn type_search() -> Attribute {
+    input_type(InputType::Search)
+}
+
+/// `type="color"` (color input)
+pub fn type_color() -> Attribute {
+    input_type(InputType::Color)
+}
+
+/// `type="time"` (time input)
+pub fn type_time() -> Attribute {
+    input_type(InputType::Time)
+}
+
+/// `type="week"` (week input)
+pub fn type_week() -> Attribute {
+    input_type(InputType::Week)
+}
+
+/// `type="datetime-local"` (local date and time input)
+pub fn type_datetime_local() -> Attribute {
+    input_type(InputType::DatetimeLocal)
+}
+
+/// `type="image"` (image submit button)
+pub fn type_image() -> Attribute {
+    input_type(InputType::Image)
+}
+
+/// `type="button"` (push button)
+pub fn type_button() -> Attribute {
+    input_type(InputType::Button)
 }
 
 /// `integrity` attribute
@@ -246,14 +1614,34 @@ pub fn async_() -> Attribute {
     Attribute::new_flag("async")
 }
 
+/// CORS mode of a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CrossOrigin {
+    /// `anonymous` (credentials only sent to the same origin)
+    Anonymous,
+    /// `use-credentials`
+    UseCredentials,
+}
+
+/// `crossorigin` attribute
+pub fn crossorigin(value: CrossOrigin) -> Attribute {
+    Attribute::new(
+        "crossorigin",
+        match value {
+            CrossOrigin::Anonymous => "anonymous",
+            CrossOrigin::UseCredentials => "use-credentials",
+        },
+    )
+}
+
 /// `crossorigin="anonymous"`
 pub fn crossorigin_anonymous() -> Attribute {
-    Attribute::new("crossorigin", "anonymous")
+    crossorigin(CrossOrigin::Anonymous)
 }
 
 /// `crossorigin="use-credentials"`
 pub fn crossorigin_use_credentials() -> Attribute {
-    Attribute::new("crossorigin", "use-credentials")
+    crossorigin(CrossOrigin::UseCredentials)
 }
 
 /// `download` flag attribute
@@ -297,14 +1685,121 @@ pub fn action(action: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("action", action)
 }
 
-/// `method_get` attribute
+/// HTTP method used to submit a form
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormMethod {
+    /// `get`
+    Get,
+    /// `post`
+    Post,
+    /// `dialog` (closes the dialog containing the form, without submitting it)
+    Dialog,
+}
+
+impl FormMethod {
+    fn as_str(self) -> &'static str {
+        match self {
+            FormMethod::Get => "get",
+            FormMethod::Post => "post",
+            FormMethod::Dialog => "dialog",
+        }
+    }
+}
+
+/// `method` attribute (for `<form>`)
+pub fn method(value: FormMethod) -> Attribute {
+    Attribute::new("method", value.as_str())
+}
+
+/// `method="get"` attribute
 pub fn method_get() -> Attribute {
-    Attribute::new("method", "get")
+    method(FormMethod::Get)
 }
 
-/// `method_get` attribute
+/// `method="post"` attribute
 pub fn method_post() -> Attribute {
-    Attribute::new("method", "post")
+    method(FormMethod::Post)
+}
+
+/// `method="dialog"` attribute (closes the dialog containing the form)
+pub fn method_dialog() -> Attribute {
+    method(FormMethod::Dialog)
+}
+
+/// Encoding of the data submitted by a form
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Enctype {
+    /// `application/x-www-form-urlencoded` (default)
+    UrlEncoded,
+    /// `multipart/form-data` (required to upload files)
+    Multipart,
+    /// `text/plain`
+    TextPlain,
+}
+
+impl Enctype {
+    fn as_str(self) -> &'static str {
+        match self {
+            Enctype::UrlEncoded => "application/x-www-form-urlencoded",
+            Enctype::Multipart => "multipart/form-data",
+            Enctype::TextPlain => "text/plain",
+        }
+    }
+}
+
+/// `enctype` attribute (for `<form>`)
+pub fn enctype(value: Enctype) -> Attribute {
+    Attribute::new("enctype", value.as_str())
+}
+
+/// `novalidate` attribute (for `<form>`)
+pub fn novalidate() -> Attribute {
+    Attribute::new_flag("novalidate")
+}
+
+/// `form` attribute (id of the form owning the element)
+pub fn form(id: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("form", id)
+}
+
+/// `formaction` attribute (for submit buttons)
+pub fn formaction(url: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("formaction", url)
+}
+
+/// `formmethod` attribute (for submit buttons)
+pub fn formmethod(value: FormMethod) -> Attribute {
+    Attribute::new("formmethod", value.as_str())
+}
+
+/// `formmethod="get"` attribute (for submit buttons)
+pub fn formmethod_get() -> Attribute {
+    formmethod(FormMethod::Get)
+}
+
+/// `formmethod="post"` attribute (for submit buttons)
+pub fn formmethod_post() -> Attribute {
+    formmethod(FormMethod::Post)
+}
+
+/// `formmethod="dialog"` attribute (for submit buttons)
+pub fn formmethod_dialog() -> Attribute {
+    formmethod(FormMethod::Dialog)
+}
+
+/// `formenctype` attribute (for submit buttons)
+pub fn formenctype(value: Enctype) -> Attribute {
+    Attribute::new("formenctype", value.as_str())
+}
+
+/// `formnovalidate` attribute (for submit buttons)
+pub fn formnovalidate() -> Attribute {
+    Attribute::new_flag("formnovalidate")
+}
+
+/// `formtarget` attribute (for submit buttons)
+pub fn formtarget(target: AnchorTarget) -> Attribute {
+    Attribute::new("formtarget", target.into_value())
 }
 
 /// `for` attribute
@@ -317,6 +1812,155 @@ pub fn value(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("value", value)
 }
 
+/// `value` attribute with a date or time value (for `date`, `time` and `datetime-local` inputs)
+///
+/// See [`HtmlDateTime`] for the supported values
+pub fn value_date(value: impl Into<HtmlDateTime>) -> Attribute {
+    Attribute::new("value", value.into())
+}
+
+/// `value` attribute with a numeric value (e.g. for `<progress>`, `<meter>` or numeric inputs)
+///
+/// The value must not be a NaN or an infinite float, which is checked in debug builds
+pub fn value_number(value: impl Into<Number>) -> Attribute {
+    Attribute::new_number("value", value)
+}
+
+/// `datetime` attribute (for `<time>`, `<ins>` and `<del>`)
+///
+/// See [`HtmlDateTime`] for the supported values
+pub fn datetime(value: impl Into<HtmlDateTime>) -> Attribute {
+    Attribute::new("datetime", value.into())
+}
+
+/// `cite` attribute (URL of the source, for `<q>`, `<blockquote>`, `<ins>` and `<del>`)
+pub fn cite(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("cite", value)
+}
+
+/// `open` attribute (for `<details>` and `<dialog>`)
+pub fn open() -> Attribute {
+    Attribute::new_flag("open")
+}
+
+/// Command invoked by a button on the element designated by [`commandfor`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Command {
+    /// `show-modal` (for `<dialog>`)
+    ShowModal,
+    /// `close` (for `<dialog>`)
+    Close,
+    /// `request-close` (for `<dialog>`)
+    RequestClose,
+    /// `show-popover`
+    ShowPopover,
+    /// `hide-popover`
+    HidePopover,
+    /// `toggle-popover`
+    TogglePopover,
+    /// Custom command, handled by a `command` event listener
+    ///
+    /// The name must start with `--` (e.g. `--rotate`)
+    Custom(Cow<'static, str>),
+}
+
+/// `command` attribute
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr::{self, Command}, elt};
+/// let element = elt::button(
+///     [attr::commandfor("confirm"), attr::command(Command::ShowModal)],
+///     ["Delete".into()],
+/// );
+///
+/// assert_eq!(
+///     element.to_string(),
+///     r#"<button commandfor="confirm" command="show-modal">Delete</button>"#,
+/// );
+/// ```
+pub fn command(command: Command) -> Attribute {
+    Attribute::new(
+        "command",
+        match command {
+            Command::ShowModal => "show-modal".into(),
+            Command::Close => "close".into(),
+            Command::RequestClose => "request-close".into(),
+            Command::ShowPopover => "show-popover".into(),
+            Command::HidePopover => "hide-popover".into(),
+            Command::TogglePopover => "toggle-popover".into(),
+            Command::Custom(name) => {
+                debug_assert!(
+                    name.starts_with("--"),
+                    "custom command should start with '--': {name}"
+                );
+                name
+            }
+        },
+    )
+}
+
+/// `commandfor` attribute (id of the element controlled by a button)
+pub fn commandfor(id: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("commandfor", id)
+}
+
+/// `checked` attribute (for checkboxes and radio buttons)
+pub fn checked() -> Attribute {
+    Attribute::new_flag("checked")
+}
+
+/// `selected` attribute (for `<option>`)
+pub fn selected() -> Attribute {
+    Attribute::new_flag("selected")
+}
+
+/// `readonly` attribute
+pub fn readonly() -> Attribute {
+    Attribute::new_flag("readonly")
+}
+
+/// `list` attribute (id of a `<datalist>` providing suggestions to an input)
+pub fn list(id: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("list", id)
+}
+
+/// `size` attribute (visible width of an input in characters, or number of visible options of a select)
+pub fn size(value: u16) -> Attribute {
+    Attribute::new_int("size", value.into())
+}
+
+/// `accept` attribute (comma-separated list of file types accepted by a file input)
+pub fn accept(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("accept", value)
+}
+
+/// Camera used to capture a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capture {
+    /// `user` (front camera)
+    User,
+    /// `environment` (rear camera)
+    Environment,
+}
+
+/// `capture` attribute (for file inputs)
+pub fn capture(value: Capture) -> Attribute {
+    Attribute::new(
+        "capture",
+        match value {
+            Capture::User => "user",
+            Capture::Environment => "environment",
+        },
+    )
+}
+
+/// `dirname` attribute (name of the field submitting the direction of the text)
+pub fn dirname(name: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("dirname", name)
+}
+
 /// `required` attribute
 pub fn required() -> Attribute {
     Attribute::new_flag("required")
@@ -327,6 +1971,8 @@ pub fn autofocus() -> Attribute {
 }
 
 /// `autocomplete` attribute
+///
+/// It accepts an [`Autocomplete`], a [`Field`] or a [`ContactField`] to only produce valid autofill tokens
 pub fn autocomplete(type_: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("autocomplete", type_)
 }
@@ -336,7 +1982,7 @@ pub fn autocomplete_on() -> Attribute {
     Attribute::new("autocomplete", "on")
 }
 
-/// `autocomplete="on"` attribute
+/// `autocomplete="off"` attribute
 pub fn autocomplete_off() -> Attribute {
     Attribute::new("autocomplete", "off")
 }
@@ -361,6 +2007,71 @@ pub fn max(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("max", value)
 }
 
+/// `step` attribute (a number, or `any`)
+pub fn step(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("step", value)
+}
+
+/// `min` attribute with a date or time value (for `date`, `time` and `datetime-local` inputs)
+///
+/// See [`HtmlDateTime`] for the supported values
+pub fn min_date(value: impl Into<HtmlDateTime>) -> Attribute {
+    Attribute::new("min", value.into())
+}
+
+/// `max` attribute with a date or time value (for `date`, `time` and `datetime-local` inputs)
+///
+/// See [`HtmlDateTime`] for the supported values
+pub fn max_date(value: impl Into<HtmlDateTime>) -> Attribute {
+    Attribute::new("max", value.into())
+}
+
+/// `min` attribute with a numeric value
+///
+/// The value must not be a NaN or an infinite float, which is checked in debug builds
+pub fn min_number(value: impl Into<Number>) -> Attribute {
+    Attribute::new_number("min", value)
+}
+
+/// `max` attribute with a numeric value
+///
+/// The value must not be a NaN or an infinite float, which is checked in debug builds
+pub fn max_number(value: impl Into<Number>) -> Attribute {
+    Attribute::new_number("max", value)
+}
+
+/// `step` attribute with a numeric value
+///
+/// The value must not be a NaN or an infinite float, which is checked in debug builds
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr, elt};
+/// assert_eq!(
+///     elt::input([attr::type_number(), attr::min_number(0), attr::step_number(0.1)]).to_string(),
+///     r#"<input type="number" min="0" step="0.1">"#,
+/// );
+/// ```
+pub fn step_number(value: impl Into<Number>) -> Attribute {
+    Attribute::new_number("step", value)
+}
+
+/// `low` attribute (for `<meter>`)
+pub fn low(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("low", value)
+}
+
+/// `high` attribute (for `<meter>`)
+pub fn high(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("high", value)
+}
+
+/// `optimum` attribute (for `<meter>`)
+pub fn optimum(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("optimum", value)
+}
+
 /// `minlength` attribute
 pub fn minlength(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("minlength", value)
@@ -391,6 +2102,11 @@ pub fn placeholder(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("placeholder", value)
 }
 
+/// `role` attribute
+pub fn role(role: aria::Role) -> Attribute {
+    Attribute::new("role", role.as_str())
+}
+
 /// `role` attribute with an aribtrary string value
 pub fn role_str(value: impl Into<Cow<'static, str>>) -> Attribute {
     Attribute::new("role", value)
@@ -0,0 +1,620 @@
+//! ARIA roles, states and properties
+//!
+//! Boolean states are rendered as `"true"` or `"false"` (and not as flag attributes),
+//! as required by the [WAI-ARIA](https://www.w3.org/TR/wai-aria-1.2/) specification.
+//!
+//! ## Example
+//!
+//! ```
+//! # use fun_html::{attr::{self, aria::{self, Role}}, elt};
+//! let element = elt::button(
+//!     [
+//!         attr::role(Role::Switch),
+//!         aria::aria_checked(true),
+//!         aria::aria_controls(["panel-1", "panel-2"]),
+//!     ],
+//!     ["Details".into()],
+//! );
+//!
+//! assert_eq!(
+//!     element.to_string(),
+//!     r#"<button role="switch" aria-checked="true" aria-controls="panel-1 panel-2">Details</button>"#,
+//! );
+//! ```
+
+use alloc::{borrow::Cow, fmt::Display, string::String, vec::Vec};
+
+use crate::Attribute;
+
+macro_rules! roles {
+    ($($(#[$attr:meta])* $variant:ident => $name:literal,)*) => {
+        /// WAI-ARIA 1.2 role (abstract roles excluded)
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum Role {
+            $(
+                #[doc = concat!("`", $name, "`")]
+                $(#[$attr])*
+                $variant,
+            )*
+        }
+
+        impl Role {
+            /// Value of the `role` attribute (e.g. `button`)
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(Role::$variant => $name,)*
+                }
+            }
+        }
+    };
+}
+
+roles! {
+    Alert => "alert",
+    AlertDialog => "alertdialog",
+    Application => "application",
+    Article => "article",
+    Banner => "banner",
+    Blockquote => "blockquote",
+    Button => "button",
+    Caption => "caption",
+    Cell => "cell",
+    Checkbox => "checkbox",
+    Code => "code",
+    ColumnHeader => "columnheader",
+    Combobox => "combobox",
+    Complementary => "complementary",
+    ContentInfo => "contentinfo",
+    Definition => "definition",
+    Deletion => "deletion",
+    Dialog => "dialog",
+    Document => "document",
+    Emphasis => "emphasis",
+    Feed => "feed",
+    Figure => "figure",
+    Form => "form",
+    Generic => "generic",
+    Grid => "grid",
+    GridCell => "gridcell",
+    Group => "group",
+    Heading => "heading",
+    Img => "img",
+    Insertion => "insertion",
+    Link => "link",
+    List => "list",
+    Listbox => "listbox",
+    ListItem => "listitem",
+    Log => "log",
+    Main => "main",
+    Marquee => "marquee",
+    Math => "math",
+    Menu => "menu",
+    MenuBar => "menubar",
+    MenuItem => "menuitem",
+    MenuItemCheckbox => "menuitemcheckbox",
+    MenuItemRadio => "menuitemradio",
+    Meter => "meter",
+    Navigation => "navigation",
+    None => "none",
+    Note => "note",
+    Option => "option",
+    Paragraph => "paragraph",
+    Presentation => "presentation",
+    ProgressBar => "progressbar",
+    Radio => "radio",
+    RadioGroup => "radiogroup",
+    Region => "region",
+    Row => "row",
+    RowGroup => "rowgroup",
+    RowHeader => "rowheader",
+    Scrollbar => "scrollbar",
+    Search => "search",
+    Searchbox => "searchbox",
+    Separator => "separator",
+    Slider => "slider",
+    SpinButton => "spinbutton",
+    Status => "status",
+    Strong => "strong",
+    Subscript => "subscript",
+    Superscript => "superscript",
+    Switch => "switch",
+    Tab => "tab",
+    Table => "table",
+    TabList => "tablist",
+    TabPanel => "tabpanel",
+    Term => "term",
+    Textbox => "textbox",
+    Time => "time",
+    Timer => "timer",
+    Toolbar => "toolbar",
+    Tooltip => "tooltip",
+    Tree => "tree",
+    TreeGrid => "treegrid",
+    TreeItem => "treeitem",
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// List of element ids, for ID reference list properties (e.g. `aria-controls`)
+///
+/// It can be created from a single id, or from an array or vector of ids
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdRefs(Cow<'static, str>);
+
+impl IdRefs {
+    pub(crate) fn into_inner(self) -> Cow<'static, str> {
+        self.0
+    }
+}
+
+impl From<&'static str> for IdRefs {
+    fn from(id: &'static str) -> Self {
+        Self(id.into())
+    }
+}
+
+impl From<String> for IdRefs {
+    fn from(id: String) -> Self {
+        Self(id.into())
+    }
+}
+
+impl From<Cow<'static, str>> for IdRefs {
+    fn from(id: Cow<'static, str>) -> Self {
+        Self(id)
+    }
+}
+
+impl<const N: usize> From<[&str; N]> for IdRefs {
+    fn from(ids: [&str; N]) -> Self {
+        Self(ids.join(" ").into())
+    }
+}
+
+impl From<&[&str]> for IdRefs {
+    fn from(ids: &[&str]) -> Self {
+        Self(ids.join(" ").into())
+    }
+}
+
+impl From<Vec<String>> for IdRefs {
+    fn from(ids: Vec<String>) -> Self {
+        Self(ids.join(" ").into())
+    }
+}
+
+/// Value of tristate properties (`aria-checked` and `aria-pressed`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tristate {
+    /// `false`
+    False,
+    /// `true`
+    True,
+    /// `mixed`
+    Mixed,
+}
+
+impl From<bool> for Tristate {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
+
+/// Value of the `aria-live` property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Politeness {
+    /// `off`
+    Off,
+    /// `polite`
+    Polite,
+    /// `assertive`
+    Assertive,
+}
+
+/// Value of the `aria-current` state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Current {
+    /// `false`
+    False,
+    /// `true`
+    True,
+    /// `page`
+    Page,
+    /// `step`
+    Step,
+    /// `location`
+    Location,
+    /// `date`
+    Date,
+    /// `time`
+    Time,
+}
+
+/// Value of the `aria-haspopup` property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HasPopup {
+    /// `false`
+    False,
+    /// `true` (equivalent to `menu`)
+    True,
+    /// `menu`
+    Menu,
+    /// `listbox`
+    Listbox,
+    /// `tree`
+    Tree,
+    /// `grid`
+    Grid,
+    /// `dialog`
+    Dialog,
+}
+
+/// Value of the `aria-sort` property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sort {
+    /// `none`
+    None,
+    /// `ascending`
+    Ascending,
+    /// `descending`
+    Descending,
+    /// `other`
+    Other,
+}
+
+/// Value of the `aria-orientation` property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// `horizontal`
+    Horizontal,
+    /// `vertical`
+    Vertical,
+}
+
+/// Value of the `aria-autocomplete` property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Autocomplete {
+    /// `none`
+    None,
+    /// `inline`
+    Inline,
+    /// `list`
+    List,
+    /// `both`
+    Both,
+}
+
+/// Value of the `aria-invalid` state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Invalid {
+    /// `false`
+    False,
+    /// `true`
+    True,
+    /// `grammar`
+    Grammar,
+    /// `spelling`
+    Spelling,
+}
+
+fn boolean(name: &'static str, value: bool) -> Attribute {
+    Attribute::new(name, if value { "true" } else { "false" })
+}
+
+fn tristate(name: &'static str, value: Tristate) -> Attribute {
+    Attribute::new(
+        name,
+        match value {
+            Tristate::False => "false",
+            Tristate::True => "true",
+            Tristate::Mixed => "mixed",
+        },
+    )
+}
+
+fn number(name: &'static str, value: f64) -> Attribute {
+    Attribute::new_number(name, value)
+}
+
+/// `aria-activedescendant` property
+pub fn aria_activedescendant(id: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-activedescendant", id)
+}
+
+/// `aria-atomic` property
+pub fn aria_atomic(value: bool) -> Attribute {
+    boolean("aria-atomic", value)
+}
+
+/// `aria-autocomplete` property
+pub fn aria_autocomplete(value: Autocomplete) -> Attribute {
+    Attribute::new(
+        "aria-autocomplete",
+        match value {
+            Autocomplete::None => "none",
+            Autocomplete::Inline => "inline",
+            Autocomplete::List => "list",
+            Autocomplete::Both => "both",
+        },
+    )
+}
+
+/// `aria-busy` state
+pub fn aria_busy(value: bool) -> Attribute {
+    boolean("aria-busy", value)
+}
+
+/// `aria-checked` state
+pub fn aria_checked(value: impl Into<Tristate>) -> Attribute {
+    tristate("aria-checked", value.into())
+}
+
+/// `aria-colcount` property
+pub fn aria_colcount(value: i32) -> Attribute {
+    Attribute::new_int("aria-colcount", value)
+}
+
+/// `aria-colindex` property
+pub fn aria_colindex(value: i32) -> Attribute {
+    Attribute::new_int("aria-colindex", value)
+}
+
+/// `aria-colspan` property
+pub fn aria_colspan(value: i32) -> Attribute {
+    Attribute::new_int("aria-colspan", value)
+}
+
+/// `aria-controls` property
+pub fn aria_controls(ids: impl Into<IdRefs>) -> Attribute {
+    Attribute::new("aria-controls", ids.into().0)
+}
+
+/// `aria-current` state
+pub fn aria_current(value: Current) -> Attribute {
+    Attribute::new(
+        "aria-current",
+        match value {
+            Current::False => "false",
+            Current::True => "true",
+            Current::Page => "page",
+            Current::Step => "step",
+            Current::Location => "location",
+            Current::Date => "date",
+            Current::Time => "time",
+        },
+    )
+}
+
+/// `aria-describedby` property
+pub fn aria_describedby(ids: impl Into<IdRefs>) -> Attribute {
+    Attribute::new("aria-describedby", ids.into().0)
+}
+
+/// `aria-description` property
+pub fn aria_description(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-description", value)
+}
+
+/// `aria-details` property
+pub fn aria_details(id: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-details", id)
+}
+
+/// `aria-disabled` state
+pub fn aria_disabled(value: bool) -> Attribute {
+    boolean("aria-disabled", value)
+}
+
+/// `aria-errormessage` property
+pub fn aria_errormessage(id: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-errormessage", id)
+}
+
+/// `aria-expanded` state
+pub fn aria_expanded(value: bool) -> Attribute {
+    boolean("aria-expanded", value)
+}
+
+/// `aria-flowto` property
+pub fn aria_flowto(ids: impl Into<IdRefs>) -> Attribute {
+    Attribute::new("aria-flowto", ids.into().0)
+}
+
+/// `aria-haspopup` property
+pub fn aria_haspopup(value: HasPopup) -> Attribute {
+    Attribute::new(
+        "aria-haspopup",
+        match value {
+            HasPopup::False => "false",
+            HasPopup::True => "true",
+            HasPopup::Menu => "menu",
+            HasPopup::Listbox => "listbox",
+            HasPopup::Tree => "tree",
+            HasPopup::Grid => "grid",
+            HasPopup::Dialog => "dialog",
+        },
+    )
+}
+
+/// `aria-hidden` state
+pub fn aria_hidden(value: bool) -> Attribute {
+    boolean("aria-hidden", value)
+}
+
+/// `aria-invalid` state
+pub fn aria_invalid(value: Invalid) -> Attribute {
+    Attribute::new(
+        "aria-invalid",
+        match value {
+            Invalid::False => "false",
+            Invalid::True => "true",
+            Invalid::Grammar => "grammar",
+            Invalid::Spelling => "spelling",
+        },
+    )
+}
+
+/// `aria-keyshortcuts` property
+pub fn aria_keyshortcuts(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-keyshortcuts", value)
+}
+
+/// `aria-label` property
+pub fn aria_label(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-label", value)
+}
+
+/// `aria-labelledby` property
+pub fn aria_labelledby(ids: impl Into<IdRefs>) -> Attribute {
+    Attribute::new("aria-labelledby", ids.into().0)
+}
+
+/// `aria-level` property
+pub fn aria_level(value: i32) -> Attribute {
+    Attribute::new_int("aria-level", value)
+}
+
+/// `aria-live` property
+pub fn aria_live(value: Politeness) -> Attribute {
+    Attribute::new(
+        "aria-live",
+        match value {
+            Politeness::Off => "off",
+            Politeness::Polite => "polite",
+            Politeness::Assertive => "assertive",
+        },
+    )
+}
+
+/// `aria-modal` property
+pub fn aria_modal(value: bool) -> Attribute {
+    boolean("aria-modal", value)
+}
+
+/// `aria-multiline` property
+pub fn aria_multiline(value: bool) -> Attribute {
+    boolean("aria-multiline", value)
+}
+
+/// `aria-multiselectable` property
+pub fn aria_multiselectable(value: bool) -> Attribute {
+    boolean("aria-multiselectable", value)
+}
+
+/// `aria-orientation` property
+pub fn aria_orientation(value: Orientation) -> Attribute {
+    Attribute::new(
+        "aria-orientation",
+        match value {
+            Orientation::Horizontal => "horizontal",
+            Orientation::Vertical => "vertical",
+        },
+    )
+}
+
+/// `aria-owns` property
+pub fn aria_owns(ids: impl Into<IdRefs>) -> Attribute {
+    Attribute::new("aria-owns", ids.into().0)
+}
+
+/// `aria-placeholder` property
+pub fn aria_placeholder(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-placeholder", value)
+}
+
+/// `aria-posinset` property
+pub fn aria_posinset(value: i32) -> Attribute {
+    Attribute::new_int("aria-posinset", value)
+}
+
+/// `aria-pressed` state
+pub fn aria_pressed(value: impl Into<Tristate>) -> Attribute {
+    tristate("aria-pressed", value.into())
+}
+
+/// `aria-readonly` property
+pub fn aria_readonly(value: bool) -> Attribute {
+    boolean("aria-readonly", value)
+}
+
+/// `aria-relevant` property (space-separated list of `additions`, `removals`, `text` or `all`)
+pub fn aria_relevant(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-relevant", value)
+}
+
+/// `aria-required` property
+pub fn aria_required(value: bool) -> Attribute {
+    boolean("aria-required", value)
+}
+
+/// `aria-roledescription` property
+pub fn aria_roledescription(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-roledescription", value)
+}
+
+/// `aria-rowcount` property
+pub fn aria_rowcount(value: i32) -> Attribute {
+    Attribute::new_int("aria-rowcount", value)
+}
+
+/// `aria-rowindex` property
+pub fn aria_rowindex(value: i32) -> Attribute {
+    Attribute::new_int("aria-rowindex", value)
+}
+
+/// `aria-rowspan` property
+pub fn aria_rowspan(value: i32) -> Attribute {
+    Attribute::new_int("aria-rowspan", value)
+}
+
+/// `aria-selected` state
+pub fn aria_selected(value: bool) -> Attribute {
+    boolean("aria-selected", value)
+}
+
+/// `aria-setsize` property (`-1` if the size is unknown)
+pub fn aria_setsize(value: i32) -> Attribute {
+    Attribute::new_int("aria-setsize", value)
+}
+
+/// `aria-sort` property
+pub fn aria_sort(value: Sort) -> Attribute {
+    Attribute::new(
+        "aria-sort",
+        match value {
+            Sort::None => "none",
+            Sort::Ascending => "ascending",
+            Sort::Descending => "descending",
+            Sort::Other => "other",
+        },
+    )
+}
+
+/// `aria-valuemax` property
+pub fn aria_valuemax(value: f64) -> Attribute {
+    number("aria-valuemax", value)
+}
+
+/// `aria-valuemin` property
+pub fn aria_valuemin(value: f64) -> Attribute {
+    number("aria-valuemin", value)
+}
+
+/// `aria-valuenow` property
+pub fn aria_valuenow(value: f64) -> Attribute {
+    number("aria-valuenow", value)
+}
+
+/// `aria-valuetext` property
+pub fn aria_valuetext(value: impl Into<Cow<'static, str>>) -> Attribute {
+    Attribute::new("aria-valuetext", value)
+}
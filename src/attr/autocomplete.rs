@@ -0,0 +1,377 @@
+use alloc::{borrow::Cow, fmt::Display, string::ToString};
+use core::fmt;
+
+/// Autofill detail tokens of the `autocomplete` attribute
+///
+/// The tokens are rendered in the order required by the HTML specification:
+/// section, `shipping` or `billing`, contact type, field name and `webauthn`.
+///
+/// Contact types (like `mobile`) are only available on a [`ContactField`], which they apply to.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::attr::{self, Autocomplete, ContactField, Field};
+/// # use fun_html::elt;
+/// let element = elt::input([
+///     attr::type_tel(),
+///     attr::autocomplete(ContactField::Tel.mobile().section("checkout")?.shipping()),
+/// ]);
+///
+/// assert_eq!(
+///     element.to_string(),
+///     r#"<input type="tel" autocomplete="section-checkout shipping mobile tel">"#,
+/// );
+/// assert_eq!(Autocomplete::new(Field::PostalCode).billing().to_string(), "billing postal-code");
+/// # Ok::<(), attr::InvalidSectionName>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Autocomplete {
+    section: Option<Cow<'static, str>>,
+    address: Option<&'static str>,
+    contact: Option<&'static str>,
+    field: Field,
+    webauthn: bool,
+}
+
+/// Field name of an [`Autocomplete`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Field {
+    /// `name`
+    Name,
+    /// `honorific-prefix`
+    HonorificPrefix,
+    /// `given-name`
+    GivenName,
+    /// `additional-name`
+    AdditionalName,
+    /// `family-name`
+    FamilyName,
+    /// `honorific-suffix`
+    HonorificSuffix,
+    /// `nickname`
+    Nickname,
+    /// `username`
+    Username,
+    /// `new-password`
+    NewPassword,
+    /// `current-password`
+    CurrentPassword,
+    /// `one-time-code`
+    OneTimeCode,
+    /// `organization-title`
+    OrganizationTitle,
+    /// `organization`
+    Organization,
+    /// `street-address`
+    StreetAddress,
+    /// `address-line1`
+    AddressLine1,
+    /// `address-line2`
+    AddressLine2,
+    /// `address-line3`
+    AddressLine3,
+    /// `address-level4`
+    AddressLevel4,
+    /// `address-level3`
+    AddressLevel3,
+    /// `address-level2`
+    AddressLevel2,
+    /// `address-level1`
+    AddressLevel1,
+    /// `country`
+    Country,
+    /// `country-name`
+    CountryName,
+    /// `postal-code`
+    PostalCode,
+    /// `cc-name`
+    CcName,
+    /// `cc-given-name`
+    CcGivenName,
+    /// `cc-additional-name`
+    CcAdditionalName,
+    /// `cc-family-name`
+    CcFamilyName,
+    /// `cc-number`
+    CcNumber,
+    /// `cc-exp`
+    CcExp,
+    /// `cc-exp-month`
+    CcExpMonth,
+    /// `cc-exp-year`
+    CcExpYear,
+    /// `cc-csc`
+    CcCsc,
+    /// `cc-type`
+    CcType,
+    /// `transaction-currency`
+    TransactionCurrency,
+    /// `transaction-amount`
+    TransactionAmount,
+    /// `language`
+    Language,
+    /// `bday`
+    Bday,
+    /// `bday-day`
+    BdayDay,
+    /// `bday-month`
+    BdayMonth,
+    /// `bday-year`
+    BdayYear,
+    /// `sex`
+    Sex,
+    /// `url`
+    Url,
+    /// `photo`
+    Photo,
+    /// Field accepting a contact type (see [`ContactField`])
+    Contact(ContactField),
+}
+
+/// Field name of an [`Autocomplete`] that accepts a contact type
+///
+/// The contact type is chosen with [`home`](Self::home), [`work`](Self::work), [`mobile`](Self::mobile),
+/// [`fax`](Self::fax) or [`pager`](Self::pager), which do not exist for the other fields:
+///
+/// ```compile_fail
+/// # use fun_html::attr::{Autocomplete, Field};
+/// Autocomplete::new(Field::PostalCode).mobile();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContactField {
+    /// `tel`
+    Tel,
+    /// `tel-country-code`
+    TelCountryCode,
+    /// `tel-national`
+    TelNational,
+    /// `tel-area-code`
+    TelAreaCode,
+    /// `tel-local`
+    TelLocal,
+    /// `tel-local-prefix`
+    TelLocalPrefix,
+    /// `tel-local-suffix`
+    TelLocalSuffix,
+    /// `tel-extension`
+    TelExtension,
+    /// `email`
+    Email,
+    /// `impp`
+    Impp,
+}
+
+impl Field {
+    fn as_str(self) -> &'static str {
+        match self {
+            Field::Name => "name",
+            Field::HonorificPrefix => "honorific-prefix",
+            Field::GivenName => "given-name",
+            Field::AdditionalName => "additional-name",
+            Field::FamilyName => "family-name",
+            Field::HonorificSuffix => "honorific-suffix",
+            Field::Nickname => "nickname",
+            Field::Username => "username",
+            Field::NewPassword => "new-password",
+            Field::CurrentPassword => "current-password",
+            Field::OneTimeCode => "one-time-code",
+            Field::OrganizationTitle => "organization-title",
+            Field::Organization => "organization",
+            Field::StreetAddress => "street-address",
+            Field::AddressLine1 => "address-line1",
+            Field::AddressLine2 => "address-line2",
+            Field::AddressLine3 => "address-line3",
+            Field::AddressLevel4 => "address-level4",
+            Field::AddressLevel3 => "address-level3",
+            Field::AddressLevel2 => "address-level2",
+            Field::AddressLevel1 => "address-level1",
+            Field::Country => "country",
+            Field::CountryName => "country-name",
+            Field::PostalCode => "postal-code",
+            Field::CcName => "cc-name",
+            Field::CcGivenName => "cc-given-name",
+            Field::CcAdditionalName => "cc-additional-name",
+            Field::CcFamilyName => "cc-family-name",
+            Field::CcNumber => "cc-number",
+            Field::CcExp => "cc-exp",
+            Field::CcExpMonth => "cc-exp-month",
+            Field::CcExpYear => "cc-exp-year",
+            Field::CcCsc => "cc-csc",
+            Field::CcType => "cc-type",
+            Field::TransactionCurrency => "transaction-currency",
+            Field::TransactionAmount => "transaction-amount",
+            Field::Language => "language",
+            Field::Bday => "bday",
+            Field::BdayDay => "bday-day",
+            Field::BdayMonth => "bday-month",
+            Field::BdayYear => "bday-year",
+            Field::Sex => "sex",
+            Field::Url => "url",
+            Field::Photo => "photo",
+            Field::Contact(field) => field.as_str(),
+        }
+    }
+}
+
+impl ContactField {
+    fn as_str(self) -> &'static str {
+        match self {
+            ContactField::Tel => "tel",
+            ContactField::TelCountryCode => "tel-country-code",
+            ContactField::TelNational => "tel-national",
+            ContactField::TelAreaCode => "tel-area-code",
+            ContactField::TelLocal => "tel-local",
+            ContactField::TelLocalPrefix => "tel-local-prefix",
+            ContactField::TelLocalSuffix => "tel-local-suffix",
+            ContactField::TelExtension => "tel-extension",
+            ContactField::Email => "email",
+            ContactField::Impp => "impp",
+        }
+    }
+
+    /// `home` contact type
+    pub fn home(self) -> Autocomplete {
+        self.contact("home")
+    }
+
+    /// `work` contact type
+    pub fn work(self) -> Autocomplete {
+        self.contact("work")
+    }
+
+    /// `mobile` contact type
+    pub fn mobile(self) -> Autocomplete {
+        self.contact("mobile")
+    }
+
+    /// `fax` contact type
+    pub fn fax(self) -> Autocomplete {
+        self.contact("fax")
+    }
+
+    /// `pager` contact type
+    pub fn pager(self) -> Autocomplete {
+        self.contact("pager")
+    }
+
+    fn contact(self, contact: &'static str) -> Autocomplete {
+        Autocomplete {
+            contact: Some(contact),
+            ..Autocomplete::new(self)
+        }
+    }
+}
+
+impl Autocomplete {
+    /// Autofill the field
+    pub fn new(field: impl Into<Field>) -> Self {
+        Self {
+            section: None,
+            address: None,
+            contact: None,
+            field: field.into(),
+            webauthn: false,
+        }
+    }
+
+    /// Group the field with the other fields of the same section (`section-*`)
+    ///
+    /// Returns an error if the name is empty or contains whitespace, as it must be a single token
+    pub fn section(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, InvalidSectionName> {
+        let name = name.into();
+        if name.is_empty() || name.contains(|c: char| c.is_ascii_whitespace()) {
+            return Err(InvalidSectionName(name));
+        }
+        self.section = Some(name);
+        Ok(self)
+    }
+
+    /// `shipping` (the field is part of the shipping address or contact information)
+    pub fn shipping(mut self) -> Self {
+        self.address = Some("shipping");
+        self
+    }
+
+    /// `billing` (the field is part of the billing address or contact information)
+    pub fn billing(mut self) -> Self {
+        self.address = Some("billing");
+        self
+    }
+
+    /// `webauthn` (the field can be filled with a passkey)
+    pub fn webauthn(mut self) -> Self {
+        self.webauthn = true;
+        self
+    }
+}
+
+/// Error returned by [`Autocomplete::section`] when the name is not a single token
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvalidSectionName(pub Cow<'static, str>);
+
+impl Display for InvalidSectionName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid autocomplete section name: {:?}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidSectionName {}
+
+impl Display for Autocomplete {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(section) = &self.section {
+            write!(f, "section-{section} ")?;
+        }
+        for token in self.address.iter().chain(&self.contact) {
+            write!(f, "{token} ")?;
+        }
+        f.write_str(self.field.as_str())?;
+        if self.webauthn {
+            f.write_str(" webauthn")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Field> for Autocomplete {
+    fn from(field: Field) -> Self {
+        Self::new(field)
+    }
+}
+
+impl From<ContactField> for Field {
+    fn from(field: ContactField) -> Self {
+        Field::Contact(field)
+    }
+}
+
+impl From<ContactField> for Autocomplete {
+    fn from(field: ContactField) -> Self {
+        Self::new(field)
+    }
+}
+
+impl From<Autocomplete> for Cow<'static, str> {
+    fn from(autocomplete: Autocomplete) -> Self {
+        autocomplete.to_string().into()
+    }
+}
+
+impl From<Field> for Cow<'static, str> {
+    fn from(field: Field) -> Self {
+        field.as_str().into()
+    }
+}
+
+impl From<ContactField> for Cow<'static, str> {
+    fn from(field: ContactField) -> Self {
+        field.as_str().into()
+    }
+}
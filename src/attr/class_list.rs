@@ -0,0 +1,204 @@
+use alloc::{borrow::Cow, fmt::Display, string::String};
+
+use crate::Attribute;
+
+/// List of classes, without duplicates
+///
+/// Classes can be added from `&str`, `String` or `Cow<str>` values, from `(class, condition)` pairs,
+/// and from `Option`s (see [`ClassEntry`]). Values containing whitespace are split into multiple classes.
+///
+/// The [`classes!`](crate::classes) macro is a shorthand to build a list.
+///
+/// When an element has multiple `class` attributes, they are merged into the first one
+/// when the element is created.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr::{self, ClassList}, elt};
+/// let selected = true;
+/// let mut classes = ClassList::new();
+/// classes.push("tab");
+/// classes.push(("active", selected));
+/// classes.push(format!("tab-{}", 2));
+/// classes.push("tab");
+///
+/// assert_eq!(
+///     elt::div([attr::class_list(classes)], []).to_string(),
+///     r#"<div class="tab active tab-2"></div>"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ClassList {
+    classes: String,
+    #[cfg(feature = "tailwind")]
+    tailwind: bool,
+}
+
+/// Value that can be added to a [`ClassList`]
+pub trait ClassEntry {
+    /// Classes to add (separated by whitespace), if any
+    fn classes(&self) -> Option<&str>;
+}
+
+impl ClassList {
+    /// Empty list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Empty list resolving conflicting Tailwind utilities
+    ///
+    /// When classes are added, the Tailwind utilities that conflict with them are removed
+    /// (see [`tailwind`](super::tailwind))
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::attr::ClassList;
+    /// let mut classes = ClassList::tailwind();
+    /// classes.push("card p-2 text-sm");
+    /// classes.push("p-4");
+    ///
+    /// assert_eq!(classes.to_string(), "card text-sm p-4");
+    /// ```
+    #[cfg(feature = "tailwind")]
+    pub fn tailwind() -> Self {
+        Self {
+            classes: String::new(),
+            tailwind: true,
+        }
+    }
+
+    /// Add classes that are not already in the list
+    pub fn push(&mut self, entry: impl ClassEntry) {
+        #[cfg(feature = "tailwind")]
+        if let Some(classes) = entry.classes().filter(|_| self.tailwind) {
+            self.classes = super::tailwind::merge([&*self.classes, classes]);
+            return;
+        }
+        for class in entry.classes().unwrap_or_default().split_whitespace() {
+            if !self.contains(class) {
+                if !self.classes.is_empty() {
+                    self.classes.push(' ');
+                }
+                self.classes.push_str(class);
+            }
+        }
+    }
+
+    /// Returns `true` if the list contains the class
+    pub fn contains(&self, class: &str) -> bool {
+        self.iter().any(|c| c == class)
+    }
+
+    /// Returns `true` if the list contains no class
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+
+    pub(crate) fn into_inner(self) -> String {
+        self.classes
+    }
+
+    /// Iterate over the classes
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.classes.split(' ').filter(|c| !c.is_empty())
+    }
+}
+
+impl Display for ClassList {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.classes)
+    }
+}
+
+impl<T: ClassEntry> Extend<T> for ClassList {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for entry in iter {
+            self.push(entry);
+        }
+    }
+}
+
+impl<T: ClassEntry> FromIterator<T> for ClassList {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl From<ClassList> for Attribute {
+    fn from(value: ClassList) -> Self {
+        super::class_list(value)
+    }
+}
+
+impl ClassEntry for &str {
+    fn classes(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl ClassEntry for String {
+    fn classes(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl ClassEntry for &String {
+    fn classes(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl ClassEntry for Cow<'_, str> {
+    fn classes(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T: ClassEntry> ClassEntry for (T, bool) {
+    fn classes(&self) -> Option<&str> {
+        if self.1 {
+            self.0.classes()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: ClassEntry> ClassEntry for Option<T> {
+    fn classes(&self) -> Option<&str> {
+        self.as_ref().and_then(ClassEntry::classes)
+    }
+}
+
+/// Build a [`ClassList`](crate::attr::ClassList)
+///
+/// Each entry is either a value accepted by [`ClassList::push`](crate::attr::ClassList::push),
+/// or a class followed by `=>` and a condition.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr, classes, elt};
+/// let primary = true;
+/// let disabled = false;
+/// let extra = String::from("w-full");
+///
+/// assert_eq!(
+///     elt::button([attr::class_list(classes!["btn", "btn-primary" => primary, "disabled" => disabled, extra])], []).to_string(),
+///     r#"<button class="btn btn-primary w-full"></button>"#,
+/// );
+/// ```
+#[macro_export]
+macro_rules! classes {
+    ($($class:expr $(=> $condition:expr)?),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut list = $crate::attr::ClassList::new();
+        $(list.push(($class, true $(&& $condition)?));)*
+        list
+    }};
+}
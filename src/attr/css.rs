@@ -0,0 +1,435 @@
+//! Typed inline styles
+//!
+//! [`Style`] builds the value of the [`style`](super::style) attribute from typed properties.
+//! Free-form values (of [`Style::property`] and [`Style::custom_property`]) are escaped,
+//! so that they cannot end the declaration, or inject other declarations.
+//! Quoted strings (e.g. `"Fira Sans"`) and `!important` are kept as is.
+//!
+//! When an element has multiple `style` attributes, their declarations are merged into the first one
+//! when the element is created (later declarations override earlier ones, unless `!important`).
+//!
+//! ## Example
+//!
+//! ```
+//! # use fun_html::{attr::{self, css::{Color, DisplayValue, Length, Style}}, elt};
+//! let style = Style::new()
+//!     .display(DisplayValue::Flex)
+//!     .width(Length::percent(50))
+//!     .margin(Length::rem(1.5))
+//!     .color(Color::rgb(255, 0, 0))
+//!     .custom_property("--accent", "red; background: url(evil)");
+//!
+//! assert_eq!(
+//!     elt::div([attr::style(style)], []).to_string(),
+//!     r#"<div style="display: flex; width: 50%; margin: 1.5rem; color: rgb(255 0 0); --accent: red\3b  background: url(evil)"></div>"#,
+//! );
+//! ```
+
+use alloc::{
+    borrow::Cow,
+    fmt::{self, Write},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::Number;
+
+/// Builder of inline style declarations
+///
+/// Setting a property that is already set replaces its value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Style(Vec<(Cow<'static, str>, String)>);
+
+/// Value of the `display` property
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DisplayValue {
+    /// `none`
+    None,
+    /// `block`
+    Block,
+    /// `inline`
+    Inline,
+    /// `inline-block`
+    InlineBlock,
+    /// `flex`
+    Flex,
+    /// `inline-flex`
+    InlineFlex,
+    /// `grid`
+    Grid,
+    /// `inline-grid`
+    InlineGrid,
+    /// `flow-root`
+    FlowRoot,
+    /// `contents`
+    Contents,
+    /// `table`
+    Table,
+    /// `table-row`
+    TableRow,
+    /// `table-cell`
+    TableCell,
+    /// `list-item`
+    ListItem,
+}
+
+/// CSS length (e.g. `10px`, `50%` or `auto`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Length(LengthRepr);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum LengthRepr {
+    Auto,
+    Value(Number, &'static str),
+}
+
+/// CSS color
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Color(Cow<'static, str>);
+
+impl Style {
+    /// Empty style
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `display` property
+    #[must_use]
+    pub fn display(self, display: DisplayValue) -> Self {
+        self.set("display", display.as_str().into())
+    }
+
+    /// `width` property
+    #[must_use]
+    pub fn width(self, width: Length) -> Self {
+        self.set("width", width.to_string())
+    }
+
+    /// `height` property
+    #[must_use]
+    pub fn height(self, height: Length) -> Self {
+        self.set("height", height.to_string())
+    }
+
+    /// `color` property
+    #[must_use]
+    pub fn color(self, color: Color) -> Self {
+        self.set("color", color.0.into_owned())
+    }
+
+    /// `background-color` property
+    #[must_use]
+    pub fn background_color(self, color: Color) -> Self {
+        self.set("background-color", color.0.into_owned())
+    }
+
+    /// `margin` property (for all sides)
+    #[must_use]
+    pub fn margin(self, margin: Length) -> Self {
+        self.set("margin", margin.to_string())
+    }
+
+    /// `margin` property, with vertical (top and bottom) and horizontal (left and right) values
+    #[must_use]
+    pub fn margin_xy(self, vertical: Length, horizontal: Length) -> Self {
+        self.set("margin", alloc::format!("{vertical} {horizontal}"))
+    }
+
+    /// `padding` property (for all sides)
+    #[must_use]
+    pub fn padding(self, padding: Length) -> Self {
+        self.set("padding", padding.to_string())
+    }
+
+    /// `padding` property, with vertical (top and bottom) and horizontal (left and right) values
+    #[must_use]
+    pub fn padding_xy(self, vertical: Length, horizontal: Length) -> Self {
+        self.set("padding", alloc::format!("{vertical} {horizontal}"))
+    }
+
+    /// Custom property (e.g. `--accent`)
+    ///
+    /// The value is escaped, except for closed quoted strings and `!important`.
+    ///
+    /// The name must start with `--`, and contain only ASCII letters, digits, `-` and `_`,
+    /// which is checked in debug builds
+    #[must_use]
+    pub fn custom_property(self, name: impl Into<Cow<'static, str>>, value: &str) -> Self {
+        let name = name.into();
+        debug_assert!(
+            name.len() > 2
+                && name.starts_with("--")
+                && name
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'),
+            "invalid custom property name: {name}"
+        );
+        self.set(name, escape(value))
+    }
+
+    /// Any other property
+    ///
+    /// The value is escaped, except for closed quoted strings and `!important`.
+    ///
+    /// The name must be a valid property name (lowercase ASCII letters, digits and `-`),
+    /// which is checked in debug builds
+    #[must_use]
+    pub fn property(self, name: &'static str, value: &str) -> Self {
+        debug_assert!(
+            name.starts_with(|c: char| c.is_ascii_lowercase() || c == '-')
+                && name
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-'),
+            "invalid property name: {name}"
+        );
+        self.set(name, escape(value))
+    }
+
+    fn set(mut self, name: impl Into<Cow<'static, str>>, value: String) -> Self {
+        let name = name.into();
+        match self.0.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => *existing = value,
+            None => self.0.push((name, value)),
+        }
+        self
+    }
+}
+
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{name}: {value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Style> for Cow<'static, str> {
+    fn from(value: Style) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl DisplayValue {
+    fn as_str(self) -> &'static str {
+        match self {
+            DisplayValue::None => "none",
+            DisplayValue::Block => "block",
+            DisplayValue::Inline => "inline",
+            DisplayValue::InlineBlock => "inline-block",
+            DisplayValue::Flex => "flex",
+            DisplayValue::InlineFlex => "inline-flex",
+            DisplayValue::Grid => "grid",
+            DisplayValue::InlineGrid => "inline-grid",
+            DisplayValue::FlowRoot => "flow-root",
+            DisplayValue::Contents => "contents",
+            DisplayValue::Table => "table",
+            DisplayValue::TableRow => "table-row",
+            DisplayValue::TableCell => "table-cell",
+            DisplayValue::ListItem => "list-item",
+        }
+    }
+}
+
+macro_rules! length_units {
+    ($($name:ident => $unit:literal,)*) => {
+        $(
+            #[doc = concat!("Length in `", $unit, "`")]
+            ///
+            /// The value must not be a NaN or an infinite float, which is checked in debug builds
+            pub fn $name(value: impl Into<Number>) -> Self {
+                Self::new(value.into(), $unit)
+            }
+        )*
+    };
+}
+
+impl Length {
+    /// `auto`
+    pub const AUTO: Self = Self(LengthRepr::Auto);
+
+    length_units! {
+        px => "px",
+        em => "em",
+        rem => "rem",
+        percent => "%",
+        vw => "vw",
+        vh => "vh",
+        ch => "ch",
+    }
+
+    fn new(value: Number, unit: &'static str) -> Self {
+        debug_assert!(value.is_finite(), "invalid length: {value}{unit}");
+        Self(LengthRepr::Value(value, unit))
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            LengthRepr::Auto => f.write_str("auto"),
+            LengthRepr::Value(value, unit) => write!(f, "{value}{unit}"),
+        }
+    }
+}
+
+impl Color {
+    /// `currentcolor`
+    pub const CURRENT_COLOR: Self = Self(Cow::Borrowed("currentcolor"));
+
+    /// `transparent`
+    pub const TRANSPARENT: Self = Self(Cow::Borrowed("transparent"));
+
+    /// `rgb(r g b)`
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self(alloc::format!("rgb({red} {green} {blue})").into())
+    }
+
+    /// `rgb(r g b / alpha)`
+    ///
+    /// Alpha must be between 0 and 1, which is checked in debug builds
+    pub fn rgba(red: u8, green: u8, blue: u8, alpha: f32) -> Self {
+        debug_assert!((0.0..=1.0).contains(&alpha), "invalid alpha: {alpha}");
+        Self(alloc::format!("rgb({red} {green} {blue} / {alpha})").into())
+    }
+
+    /// Hexadecimal color (e.g. `Color::hex(0xff8800)` for `#ff8800`)
+    ///
+    /// The value must not be greater than `0xffffff`, which is checked in debug builds
+    pub fn hex(value: u32) -> Self {
+        debug_assert!(value <= 0xff_ffff, "invalid color: {value:#x}");
+        Self(alloc::format!("#{value:06x}").into())
+    }
+
+    /// Named color (e.g. `rebeccapurple`)
+    ///
+    /// The name must contain only ASCII letters, which is checked in debug builds
+    pub fn named(name: &'static str) -> Self {
+        debug_assert!(
+            !name.is_empty() && name.bytes().all(|b| b.is_ascii_alphabetic()),
+            "invalid color name: {name}"
+        );
+        Self(name.into())
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Escape characters that could end the value, the declaration or the attribute, or start a comment
+///
+/// Quotes are kept when they delimit a closed string, in which `;`, `{`, `}` and `/*` have no effect.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let mut quote = None;
+    let mut previous = None;
+    for (i, c) in value.char_indices() {
+        let dangerous = match quote {
+            Some(q) if c == q => {
+                quote = None;
+                false
+            }
+            Some(_) => matches!(c, '\\' | '<' | '>') || c.is_control(),
+            None if matches!(c, '"' | '\'') && value[i + 1..].contains(c) => {
+                quote = Some(c);
+                false
+            }
+            None => {
+                matches!(c, ';' | '{' | '}' | '"' | '\'' | '\\' | '<' | '>')
+                    || (c == '*' && previous == Some('/'))
+                    || c.is_control()
+            }
+        };
+        if dangerous {
+            let _ = write!(escaped, "\\{:x} ", u32::from(c));
+        } else {
+            escaped.push(c);
+        }
+        previous = Some(c);
+    }
+    escaped
+}
+
+/// Split a style attribute value into its declarations (name and value)
+///
+/// Semicolons and colons inside strings, parentheses or escapes do not split declarations.
+fn declarations(style: &str) -> Vec<(&str, &str)> {
+    let mut declarations = Vec::new();
+    let mut start = 0;
+    let mut depth = 0_usize;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in style.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match (c, quote) {
+            ('\\', _) => escaped = true,
+            (_, Some(q)) if c == q => quote = None,
+            (_, Some(_)) => (),
+            ('"' | '\'', None) => quote = Some(c),
+            ('(', None) => depth += 1,
+            (')', None) => depth = depth.saturating_sub(1),
+            (';', None) if depth == 0 => {
+                declarations.extend(declaration(&style[start..i]));
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    declarations.extend(declaration(&style[start..]));
+    declarations
+}
+
+fn declaration(declaration: &str) -> Option<(&str, &str)> {
+    let (name, value) = declaration.split_once(':')?;
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+    Some((name, value.trim()))
+}
+
+/// Merge the declarations of two style attribute values
+///
+/// Declarations of the second value override the ones of the first (unless they are `!important`),
+/// while keeping the position of their first occurrence.
+pub(crate) fn merge(a: &str, b: &str) -> String {
+    let mut merged: Vec<(&str, &str)> = Vec::new();
+    for (name, value) in declarations(a).into_iter().chain(declarations(b)) {
+        let existing = merged.iter_mut().find(|(n, _)| {
+            if name.starts_with("--") {
+                *n == name
+            } else {
+                n.eq_ignore_ascii_case(name)
+            }
+        });
+        match existing {
+            Some((_, existing)) if is_important(existing) && !is_important(value) => (),
+            Some((_, existing)) => *existing = value,
+            None => merged.push((name, value)),
+        }
+    }
+    let mut style = String::new();
+    for (i, (name, value)) in merged.into_iter().enumerate() {
+        if i > 0 {
+            style.push_str("; ");
+        }
+        let _ = write!(style, "{name}: {value}");
+    }
+    style
+}
+
+fn is_important(value: &str) -> bool {
+    value.rsplit_once('!').map_or(false, |(_, flag)| {
+        flag.trim().eq_ignore_ascii_case("important")
+    })
+}
@@ -0,0 +1,187 @@
+use alloc::{
+    borrow::Cow,
+    fmt::{Display, Write},
+    string::String,
+};
+
+/// Machine-readable date, time or duration value
+///
+/// It is accepted by [`datetime`](super::datetime), [`min_date`](super::min_date),
+/// [`max_date`](super::max_date) and [`value_date`](super::value_date).
+///
+/// It can be created from a string (used as is), or from a [`core::time::Duration`].
+/// The `chrono_v04` and `time_v03` features add conversions from the date and time types of these crates,
+/// which are serialized using the HTML formats:
+///
+/// | Type                                 | Format                            | Example                  |
+/// |--------------------------------------|-----------------------------------|--------------------------|
+/// | `NaiveDate`, `Date`                  | valid date string                 | `2024-03-01`             |
+/// | `NaiveTime`, `Time`                  | valid time string                 | `14:30`, `14:30:05.25`   |
+/// | `NaiveDateTime`, `PrimitiveDateTime` | valid local date and time string  | `2024-03-01T14:30`       |
+/// | `DateTime`, `OffsetDateTime`         | valid global date and time string | `2024-03-01T14:30+01:00` |
+/// | `TimeDelta`, `Duration`              | valid duration string             | `P1DT2H30M`, `PT0.5S`    |
+///
+/// Seconds are omitted when they are zero, and fractions of seconds are truncated to milliseconds.
+///
+/// Values that cannot be represented in HTML are clamped: dates before the year 1 become `0001-01-01`
+/// (at midnight for a date and time), and negative durations become `PT0S`.
+/// Years after 9999 are written with all their digits (e.g. `12345-01-01`).
+/// As HTML offsets are in whole minutes, a date and time whose offset has seconds is converted to UTC.
+///
+/// ## Example
+///
+/// ```
+/// # use core::time::Duration;
+/// # use fun_html::{attr, elt};
+/// assert_eq!(
+///     elt::time_datetime(Duration::from_secs(5400), ["1h30".into()]).to_string(),
+///     r#"<time datetime="PT1H30M">1h30</time>"#,
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HtmlDateTime(Cow<'static, str>);
+
+impl HtmlDateTime {
+    /// Valid date string (`YYYY-MM-DD`)
+    #[cfg(any(feature = "chrono_v04", feature = "time_v03"))]
+    pub(crate) fn date(year: i32, month: u8, day: u8) -> Self {
+        let mut value = String::new();
+        write_date(&mut value, year, month, day);
+        Self(value.into())
+    }
+
+    /// Valid time string (`HH:MM`, `HH:MM:SS` or `HH:MM:SS.mmm`)
+    #[cfg(any(feature = "chrono_v04", feature = "time_v03"))]
+    pub(crate) fn time(hour: u8, minute: u8, second: u8, millis: u16) -> Self {
+        let mut value = String::new();
+        write_time(&mut value, hour, minute, second, millis);
+        Self(value.into())
+    }
+
+    /// Valid normalized local date and time string (`YYYY-MM-DDTHH:MM`)
+    #[cfg(any(feature = "chrono_v04", feature = "time_v03"))]
+    pub(crate) fn local(date: (i32, u8, u8), time: (u8, u8, u8, u16)) -> Self {
+        let time = if date.0 > 0 { time } else { (0, 0, 0, 0) };
+        let mut value = String::new();
+        write_date(&mut value, date.0, date.1, date.2);
+        value.push('T');
+        write_time(&mut value, time.0, time.1, time.2, time.3);
+        Self(value.into())
+    }
+
+    /// Valid global date and time string (`YYYY-MM-DDTHH:MMZ` or `YYYY-MM-DDTHH:MM+HH:MM`)
+    #[cfg(any(feature = "chrono_v04", feature = "time_v03"))]
+    pub(crate) fn global(
+        date: (i32, u8, u8),
+        time: (u8, u8, u8, u16),
+        offset_seconds: i32,
+    ) -> Self {
+        let Self(local) = Self::local(date, time);
+        let mut value = local.into_owned();
+        if offset_seconds == 0 {
+            value.push('Z');
+        } else {
+            let sign = if offset_seconds < 0 { '-' } else { '+' };
+            let minutes = offset_seconds.unsigned_abs() / 60;
+            let _ = write!(value, "{sign}{:02}:{:02}", minutes / 60, minutes % 60);
+        }
+        Self(value.into())
+    }
+
+    /// Valid duration string (`PnDTnHnMnS`)
+    pub(crate) fn duration(seconds: u64, millis: u16) -> Self {
+        let days = seconds / 86_400;
+        let hours = seconds % 86_400 / 3600;
+        let minutes = seconds % 3600 / 60;
+        let seconds = seconds % 60;
+        let mut value = String::from("P");
+        if days > 0 {
+            let _ = write!(value, "{days}D");
+        }
+        if hours > 0 || minutes > 0 || seconds > 0 || millis > 0 || days == 0 {
+            value.push('T');
+        }
+        if hours > 0 {
+            let _ = write!(value, "{hours}H");
+        }
+        if minutes > 0 {
+            let _ = write!(value, "{minutes}M");
+        }
+        if seconds > 0 || millis > 0 || value == "PT" {
+            let _ = write!(value, "{seconds}");
+            write_millis(&mut value, millis);
+            value.push('S');
+        }
+        Self(value.into())
+    }
+
+    /// Duration from a number of seconds and nanoseconds (negative durations are clamped to zero)
+    #[cfg(any(feature = "chrono_v04", feature = "time_v03"))]
+    pub(crate) fn signed_duration(seconds: i64, nanos: i32) -> Self {
+        if seconds < 0 || nanos < 0 {
+            return Self::duration(0, 0);
+        }
+        Self::duration(seconds.unsigned_abs(), (nanos / 1_000_000) as u16)
+    }
+}
+
+#[cfg(any(feature = "chrono_v04", feature = "time_v03"))]
+fn write_date(value: &mut String, year: i32, month: u8, day: u8) {
+    if year > 0 {
+        let _ = write!(value, "{year:04}-{month:02}-{day:02}");
+    } else {
+        value.push_str("0001-01-01");
+    }
+}
+
+#[cfg(any(feature = "chrono_v04", feature = "time_v03"))]
+fn write_time(value: &mut String, hour: u8, minute: u8, second: u8, millis: u16) {
+    let _ = write!(value, "{hour:02}:{minute:02}");
+    if second > 0 || millis > 0 {
+        let _ = write!(value, ":{second:02}");
+        write_millis(value, millis);
+    }
+}
+
+fn write_millis(value: &mut String, millis: u16) {
+    if millis > 0 {
+        let fraction = alloc::format!("{millis:03}");
+        let _ = write!(value, ".{}", fraction.trim_end_matches('0'));
+    }
+}
+
+impl Display for HtmlDateTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&'static str> for HtmlDateTime {
+    fn from(value: &'static str) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<String> for HtmlDateTime {
+    fn from(value: String) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<Cow<'static, str>> for HtmlDateTime {
+    fn from(value: Cow<'static, str>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<core::time::Duration> for HtmlDateTime {
+    fn from(value: core::time::Duration) -> Self {
+        Self::duration(value.as_secs(), value.subsec_millis() as u16)
+    }
+}
+
+impl From<HtmlDateTime> for Cow<'static, str> {
+    fn from(HtmlDateTime(value): HtmlDateTime) -> Self {
+        value
+    }
+}
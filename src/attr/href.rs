@@ -0,0 +1,123 @@
+use alloc::{
+    borrow::Cow,
+    fmt::{Display, Write},
+    string::{String, ToString},
+};
+
+/// URL value
+///
+/// It is accepted by [`href`](super::href), [`src`](super::src), [`action`](super::action),
+/// [`formaction`](super::formaction), [`poster`](super::poster) and [`cite`](super::cite).
+///
+/// It can be created from a string (used as is), with the `url_v2` feature from a `url::Url`,
+/// or built from a path and query parameters with [`Href::path`] and [`Href::query`].
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr::{self, Href}, elt};
+/// let term = "fish & chips";
+/// assert_eq!(
+///     elt::a([attr::href(Href::path("/search").query("q", term).query("page", 2))], []).to_string(),
+///     r#"<a href="/search?q=fish+%26+chips&amp;page=2"></a>"#,
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Href(Cow<'static, str>);
+
+impl Href {
+    /// Relative URL with the given path
+    ///
+    /// Characters that are not allowed in a URL path (such as spaces, `?`, `#` or `\`) are percent-encoded,
+    /// and `/` is kept as a segment separator.
+    ///
+    /// The result is always a path: a `:` before the first `/` is encoded so that it cannot be read as a scheme
+    /// (e.g. `javascript:`), and so is the second `/` of a leading `//`, which would start another host.
+    pub fn path(path: &str) -> Self {
+        let mut value = String::with_capacity(path.len());
+        let mut in_first_segment = true;
+        for (index, byte) in path.bytes().enumerate() {
+            let is_scheme_separator = byte == b':' && in_first_segment;
+            let is_host_prefix = byte == b'/' && index == 1 && path.starts_with('/');
+            if is_path_byte(byte) && !is_scheme_separator && !is_host_prefix {
+                value.push(byte.into());
+            } else {
+                push_encoded(&mut value, byte);
+            }
+            in_first_segment &= byte != b'/';
+        }
+        Self(value.into())
+    }
+
+    /// Append a query parameter (before the fragment, if any)
+    ///
+    /// Name and value are encoded as `application/x-www-form-urlencoded`,
+    /// so that untrusted values cannot change the rest of the URL.
+    #[must_use]
+    pub fn query(self, name: &str, value: impl Display) -> Self {
+        let mut url = self.0.into_owned();
+        let fragment = url.find('#').map(|index| url.split_off(index));
+        url.push(if url.contains('?') { '&' } else { '?' });
+        encode_query_component(&mut url, name);
+        url.push('=');
+        encode_query_component(&mut url, &value.to_string());
+        if let Some(fragment) = fragment {
+            url.push_str(&fragment);
+        }
+        Self(url.into())
+    }
+}
+
+fn is_path_byte(byte: u8) -> bool {
+    byte.is_ascii_graphic()
+        && !matches!(
+            byte,
+            b'"' | b'#' | b'<' | b'>' | b'?' | b'\\' | b'`' | b'{' | b'}'
+        )
+}
+
+fn encode_query_component(url: &mut String, component: &str) {
+    for byte in component.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'*' | b'-' | b'.' | b'_' => {
+                url.push(byte.into());
+            }
+            b' ' => url.push('+'),
+            _ => push_encoded(url, byte),
+        }
+    }
+}
+
+fn push_encoded(url: &mut String, byte: u8) {
+    let _ = write!(url, "%{byte:02X}");
+}
+
+impl Display for Href {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&'static str> for Href {
+    fn from(value: &'static str) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<String> for Href {
+    fn from(value: String) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<Cow<'static, str>> for Href {
+    fn from(value: Cow<'static, str>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Href> for Cow<'static, str> {
+    fn from(Href(value): Href) -> Self {
+        value
+    }
+}
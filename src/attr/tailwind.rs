@@ -0,0 +1,475 @@
+//! Tailwind class conflict resolution
+//!
+//! When two [Tailwind](https://tailwindcss.com) utilities set the same CSS property
+//! (e.g. `p-2` and `p-4`), [`merge`] keeps the last one, so that the outcome does not depend on the order
+//! of the generated CSS. Utilities only conflict when they have the same variants (e.g. `hover:md:`)
+//! and importance (`!`), and a utility also overrides the more specific ones that precede it
+//! (e.g. `p-4` overrides `px-2`, but `px-2` refines `p-4`).
+//!
+//! Classes that are not recognized as Tailwind utilities are kept, without duplicates.
+//!
+//! Conflicts are only resolved where it is asked for: by [`merge`], [`class_tw`](super::class_tw)
+//! and [`ClassList::tailwind`](super::ClassList::tailwind).
+//! [`class`](super::class), [`ClassList::new`](super::ClassList::new) and the merging of repeated `class` attributes
+//! keep all the classes.
+//!
+//! ## Example
+//!
+//! ```
+//! # use fun_html::attr::tailwind;
+//! assert_eq!(
+//!     tailwind::merge(["px-2 py-1 bg-red-500 hover:bg-red-600 card", "p-3 bg-[#b91c1c] card"]),
+//!     "hover:bg-red-600 card p-3 bg-[#b91c1c]",
+//! );
+//! ```
+
+use alloc::{string::String, vec, vec::Vec};
+
+/// Merge Tailwind classes, resolving conflicts in favor of the last ones
+///
+/// Each item may contain multiple classes separated by whitespace.
+pub fn merge<'a>(classes: impl IntoIterator<Item = &'a str>) -> String {
+    let classes: Vec<&str> = classes
+        .into_iter()
+        .flat_map(str::split_whitespace)
+        .collect();
+    let mut claimed: Vec<(String, &'static str)> = Vec::new();
+    let mut keep = vec![false; classes.len()];
+    for (i, class) in classes.iter().enumerate().rev() {
+        let (modifiers, group) = match parse(class) {
+            Some(parsed) => parsed,
+            None => {
+                keep[i] = !classes[..i].contains(class);
+                continue;
+            }
+        };
+        if claimed.iter().any(|(m, g)| *m == modifiers && *g == group) {
+            continue;
+        }
+        keep[i] = true;
+        for group in core::iter::once(group).chain(conflicts(group).iter().copied()) {
+            claimed.push((modifiers.clone(), group));
+        }
+    }
+    let mut merged = String::new();
+    for (class, _) in classes.iter().zip(keep).filter(|(_, keep)| *keep) {
+        if !merged.is_empty() {
+            merged.push(' ');
+        }
+        merged.push_str(class);
+    }
+    merged
+}
+
+/// Returns the modifiers (sorted variants and importance) and the group of a Tailwind utility
+fn parse(class: &str) -> Option<(String, &'static str)> {
+    let mut variants = split_variants(class);
+    let mut base = variants.pop()?;
+    let important = if let Some(rest) = base.strip_prefix('!') {
+        base = rest;
+        true
+    } else if let Some(rest) = base.strip_suffix('!') {
+        base = rest;
+        true
+    } else {
+        false
+    };
+    let base = base.strip_prefix('-').unwrap_or(base);
+    let group = group(base)?;
+    variants.sort_unstable();
+    let mut modifiers = variants.join(":");
+    if important {
+        modifiers.push('!');
+    }
+    Some((modifiers, group))
+}
+
+/// Split a class on the `:` separating variants, ignoring the ones inside brackets or parentheses
+fn split_variants(class: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+    for (i, c) in class.char_indices() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth = depth.saturating_sub(1),
+            ':' if depth == 0 => {
+                parts.push(&class[start..i]);
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    parts.push(&class[start..]);
+    parts
+}
+
+/// Utilities without value
+const STANDALONE: &[(&str, &str)] = &[
+    ("block", "display"),
+    ("inline-block", "display"),
+    ("inline", "display"),
+    ("flex", "display"),
+    ("inline-flex", "display"),
+    ("table", "display"),
+    ("inline-table", "display"),
+    ("table-caption", "display"),
+    ("table-cell", "display"),
+    ("table-column", "display"),
+    ("table-column-group", "display"),
+    ("table-footer-group", "display"),
+    ("table-header-group", "display"),
+    ("table-row-group", "display"),
+    ("table-row", "display"),
+    ("flow-root", "display"),
+    ("grid", "display"),
+    ("inline-grid", "display"),
+    ("contents", "display"),
+    ("list-item", "display"),
+    ("hidden", "display"),
+    ("static", "position"),
+    ("fixed", "position"),
+    ("absolute", "position"),
+    ("relative", "position"),
+    ("sticky", "position"),
+    ("visible", "visibility"),
+    ("invisible", "visibility"),
+    ("collapse", "visibility"),
+    ("underline", "text-decoration-line"),
+    ("overline", "text-decoration-line"),
+    ("line-through", "text-decoration-line"),
+    ("no-underline", "text-decoration-line"),
+    ("uppercase", "text-transform"),
+    ("lowercase", "text-transform"),
+    ("capitalize", "text-transform"),
+    ("normal-case", "text-transform"),
+    ("italic", "font-style"),
+    ("not-italic", "font-style"),
+    ("truncate", "text-overflow"),
+    ("isolate", "isolation"),
+    ("isolation-auto", "isolation"),
+    ("sr-only", "sr"),
+    ("not-sr-only", "sr"),
+];
+
+/// Prefixes of utilities taking a value (e.g. `p` for `p-4`), which are also the names of their groups
+///
+/// Some groups are refined by value in [`group`].
+#[rustfmt::skip]
+const PREFIXES: &[&str] = &[
+    "p", "px", "py", "pt", "pr", "pb", "pl", "ps", "pe",
+    "m", "mx", "my", "mt", "mr", "mb", "ml", "ms", "me",
+    "space-x", "space-y", "gap", "gap-x", "gap-y",
+    "w", "h", "size", "min-w", "min-h", "max-w", "max-h",
+    "inset", "inset-x", "inset-y", "top", "right", "bottom", "left", "start", "end",
+    "z", "opacity", "order", "basis", "grow", "shrink", "flex",
+    "grid-cols", "grid-rows", "grid-flow", "auto-cols", "auto-rows",
+    "col", "col-span", "col-start", "col-end", "row", "row-span", "row-start", "row-end",
+    "justify", "justify-items", "justify-self", "items", "content", "self",
+    "place-content", "place-items", "place-self",
+    "overflow", "overflow-x", "overflow-y", "overscroll", "overscroll-x", "overscroll-y",
+    "object", "float", "clear", "box", "box-decoration", "aspect", "columns",
+    "text", "font", "leading", "tracking", "indent", "align", "whitespace", "break",
+    "break-before", "break-after", "break-inside", "line-clamp", "list", "decoration",
+    "underline-offset",
+    "bg", "bg-opacity", "text-opacity", "border-opacity", "bg-clip", "bg-origin", "bg-blend", "from", "via", "to",
+    "border", "border-x", "border-y", "border-t", "border-r", "border-b", "border-l", "border-s", "border-e",
+    "border-spacing",
+    "rounded", "rounded-t", "rounded-r", "rounded-b", "rounded-l", "rounded-s", "rounded-e",
+    "rounded-tl", "rounded-tr", "rounded-br", "rounded-bl", "rounded-ss", "rounded-se", "rounded-ee",
+    "rounded-es",
+    "divide", "divide-x", "divide-y", "outline", "outline-offset", "ring", "ring-offset",
+    "shadow", "mix-blend",
+    "blur", "brightness", "contrast", "grayscale", "hue-rotate", "invert", "saturate", "sepia",
+    "drop-shadow", "backdrop-blur", "backdrop-brightness", "backdrop-contrast", "backdrop-grayscale",
+    "backdrop-hue-rotate", "backdrop-invert", "backdrop-opacity", "backdrop-saturate", "backdrop-sepia",
+    "transition", "duration", "ease", "delay", "animate",
+    "scale", "scale-x", "scale-y", "rotate", "translate-x", "translate-y", "skew-x", "skew-y", "origin",
+    "cursor", "select", "pointer-events", "resize", "appearance",
+    "fill", "stroke", "accent", "caret", "placeholder",
+];
+
+/// Prefixes that are also utilities without value (e.g. `border` for a width of 1px)
+///
+/// Other prefixes are only recognized when followed by `-` and a value, so that `text` or `col` stay custom classes.
+#[rustfmt::skip]
+const BARE: &[&str] = &[
+    "border", "border-x", "border-y", "border-t", "border-r", "border-b", "border-l", "border-s", "border-e",
+    "rounded", "rounded-t", "rounded-r", "rounded-b", "rounded-l", "rounded-s", "rounded-e",
+    "rounded-tl", "rounded-tr", "rounded-br", "rounded-bl", "rounded-ss", "rounded-se", "rounded-ee",
+    "rounded-es",
+    "divide-x", "divide-y", "outline", "ring", "shadow", "grow", "shrink", "resize", "transition",
+    "blur", "grayscale", "invert", "sepia", "drop-shadow",
+    "backdrop-blur", "backdrop-grayscale", "backdrop-invert", "backdrop-sepia",
+];
+
+fn group(base: &str) -> Option<&'static str> {
+    if let Some((_, group)) = STANDALONE.iter().find(|(class, _)| *class == base) {
+        return Some(group);
+    }
+    let (prefix, value) = PREFIXES
+        .iter()
+        .filter_map(|prefix| {
+            let value = base.strip_prefix(prefix)?;
+            if value.is_empty() {
+                BARE.contains(prefix).then(|| (*prefix, value))
+            } else {
+                value
+                    .strip_prefix('-')
+                    .filter(|value| !value.is_empty())
+                    .map(|value| (*prefix, value))
+            }
+        })
+        .max_by_key(|(prefix, _)| prefix.len())?;
+    let group = match prefix {
+        "text" => match value {
+            "left" | "center" | "right" | "justify" | "start" | "end" => "text-align",
+            "wrap" | "nowrap" | "balance" | "pretty" => "text-wrap",
+            "ellipsis" | "clip" => "text-overflow",
+            _ if is_tshirt_size(without_modifier(value)) || is_arbitrary_length(value) => {
+                "font-size"
+            }
+            _ => "text-color",
+        },
+        "font" => match value {
+            "thin" | "extralight" | "light" | "normal" | "medium" | "semibold" | "bold"
+            | "extrabold" | "black" => "font-weight",
+            _ if is_number(value) || is_arbitrary_number(value) => "font-weight",
+            _ => "font-family",
+        },
+        "bg" => match value {
+            "fixed" | "local" | "scroll" => "bg-attachment",
+            "repeat" | "no-repeat" | "repeat-x" | "repeat-y" | "repeat-round" | "repeat-space" => {
+                "bg-repeat"
+            }
+            "auto" | "cover" | "contain" => "bg-size",
+            "bottom" | "center" | "left" | "left-bottom" | "left-top" | "right"
+            | "right-bottom" | "right-top" | "top" => "bg-position",
+            "none" => "bg-image",
+            _ if value.starts_with("gradient-") || value.starts_with("linear-") => "bg-image",
+            _ if arbitrary(value).map_or(false, |v| v.starts_with("url(")) => "bg-image",
+            _ => "bg-color",
+        },
+        "border" => match value {
+            "solid" | "dashed" | "dotted" | "double" | "hidden" | "none" => "border-style",
+            "collapse" | "separate" => "border-collapse",
+            _ if is_width(value) => "border-w",
+            _ => "border-color",
+        },
+        "border-x" | "border-y" | "border-t" | "border-r" | "border-b" | "border-l"
+        | "border-s" | "border-e" => border_side(prefix, is_width(value)),
+        "space-x" | "space-y" | "divide-x" | "divide-y" if value == "reverse" => match prefix {
+            "space-x" => "space-x-reverse",
+            "space-y" => "space-y-reverse",
+            "divide-x" => "divide-x-reverse",
+            _ => "divide-y-reverse",
+        },
+        "divide" => match value {
+            "solid" | "dashed" | "dotted" | "double" | "none" => "divide-style",
+            _ => "divide-color",
+        },
+        "outline" => match value {
+            "" | "none" | "solid" | "dashed" | "dotted" | "double" | "hidden" => "outline-style",
+            _ if is_number(value) || is_arbitrary_length(value) => "outline-w",
+            _ => "outline-color",
+        },
+        "ring" => match value {
+            "inset" => "ring-inset",
+            _ if is_width(value) => "ring-w",
+            _ => "ring-color",
+        },
+        "ring-offset" if is_width(value) => "ring-offset-w",
+        "ring-offset" => "ring-offset-color",
+        "shadow" => match value {
+            "" | "none" | "inner" => "shadow",
+            _ if is_tshirt_size(value) || arbitrary(value).is_some() => "shadow",
+            _ => "shadow-color",
+        },
+        "decoration" => match value {
+            "solid" | "double" | "dotted" | "dashed" | "wavy" => "decoration-style",
+            "auto" | "from-font" => "decoration-thickness",
+            _ if is_number(value) || is_arbitrary_length(value) => "decoration-thickness",
+            _ => "decoration-color",
+        },
+        "stroke" if is_number(value) || is_arbitrary_length(value) => "stroke-w",
+        "flex" => match value {
+            "row" | "row-reverse" | "col" | "col-reverse" => "flex-direction",
+            "wrap" | "wrap-reverse" | "nowrap" => "flex-wrap",
+            _ => "flex",
+        },
+        "justify" => "justify-content",
+        "content" => match value {
+            "normal" | "center" | "start" | "end" | "between" | "around" | "evenly"
+            | "baseline" | "stretch" => "align-content",
+            _ => "content",
+        },
+        "object" => match value {
+            "contain" | "cover" | "fill" | "none" | "scale-down" => "object-fit",
+            _ => "object-position",
+        },
+        "list" => match value {
+            "inside" | "outside" => "list-style-position",
+            _ => "list-style-type",
+        },
+        "break" => "word-break",
+        "box" => "box-sizing",
+        "col" | "col-span" => "col-start-end",
+        "row" | "row-span" => "row-start-end",
+        _ => prefix,
+    };
+    Some(group)
+}
+
+fn border_side(prefix: &str, is_width: bool) -> &'static str {
+    match (prefix, is_width) {
+        ("border-x", true) => "border-w-x",
+        ("border-y", true) => "border-w-y",
+        ("border-t", true) => "border-w-t",
+        ("border-r", true) => "border-w-r",
+        ("border-b", true) => "border-w-b",
+        ("border-l", true) => "border-w-l",
+        ("border-s", true) => "border-w-s",
+        ("border-e", true) => "border-w-e",
+        ("border-x", false) => "border-color-x",
+        ("border-y", false) => "border-color-y",
+        ("border-t", false) => "border-color-t",
+        ("border-r", false) => "border-color-r",
+        ("border-b", false) => "border-color-b",
+        ("border-l", false) => "border-color-l",
+        ("border-s", false) => "border-color-s",
+        _ => "border-color-e",
+    }
+}
+
+/// Groups overridden by a group (in addition to itself)
+fn conflicts(group: &str) -> &'static [&'static str] {
+    match group {
+        "p" => &["px", "py", "pt", "pr", "pb", "pl", "ps", "pe"],
+        "px" => &["pr", "pl"],
+        "py" => &["pt", "pb"],
+        "m" => &["mx", "my", "mt", "mr", "mb", "ml", "ms", "me"],
+        "mx" => &["mr", "ml"],
+        "my" => &["mt", "mb"],
+        "gap" => &["gap-x", "gap-y"],
+        "size" => &["w", "h"],
+        "inset" => &[
+            "inset-x", "inset-y", "top", "right", "bottom", "left", "start", "end",
+        ],
+        "inset-x" => &["right", "left"],
+        "inset-y" => &["top", "bottom"],
+        "overflow" => &["overflow-x", "overflow-y"],
+        "overscroll" => &["overscroll-x", "overscroll-y"],
+        "scale" => &["scale-x", "scale-y"],
+        "font-size" => &["leading"],
+        "rounded" => &[
+            "rounded-t",
+            "rounded-r",
+            "rounded-b",
+            "rounded-l",
+            "rounded-s",
+            "rounded-e",
+            "rounded-tl",
+            "rounded-tr",
+            "rounded-br",
+            "rounded-bl",
+            "rounded-ss",
+            "rounded-se",
+            "rounded-ee",
+            "rounded-es",
+        ],
+        "rounded-t" => &["rounded-tl", "rounded-tr"],
+        "rounded-r" => &["rounded-tr", "rounded-br"],
+        "rounded-b" => &["rounded-br", "rounded-bl"],
+        "rounded-l" => &["rounded-tl", "rounded-bl"],
+        "rounded-s" => &["rounded-ss", "rounded-es"],
+        "rounded-e" => &["rounded-se", "rounded-ee"],
+        "border-w" => &[
+            "border-w-x",
+            "border-w-y",
+            "border-w-t",
+            "border-w-r",
+            "border-w-b",
+            "border-w-l",
+            "border-w-s",
+            "border-w-e",
+        ],
+        "border-w-x" => &["border-w-r", "border-w-l"],
+        "border-w-y" => &["border-w-t", "border-w-b"],
+        "border-color" => &[
+            "border-color-x",
+            "border-color-y",
+            "border-color-t",
+            "border-color-r",
+            "border-color-b",
+            "border-color-l",
+            "border-color-s",
+            "border-color-e",
+        ],
+        "border-color-x" => &["border-color-r", "border-color-l"],
+        "border-color-y" => &["border-color-t", "border-color-b"],
+        _ => &[],
+    }
+}
+
+/// Value without its modifier (e.g. the line height of `text-lg/7`)
+fn without_modifier(value: &str) -> &str {
+    value.split('/').next().unwrap_or(value)
+}
+
+fn is_number(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit() || b == b'.')
+}
+
+/// Empty value, number, or arbitrary length (used by border and ring widths)
+fn is_width(value: &str) -> bool {
+    value.is_empty() || is_number(value) || is_arbitrary_length(value)
+}
+
+fn is_tshirt_size(value: &str) -> bool {
+    let size = value.trim_start_matches(|c: char| c.is_ascii_digit());
+    matches!(size, "xs" | "sm" | "base" | "md" | "lg" | "xl")
+}
+
+fn arbitrary(value: &str) -> Option<&str> {
+    value.strip_prefix('[')?.strip_suffix(']')
+}
+
+fn is_arbitrary_number(value: &str) -> bool {
+    arbitrary(value).map_or(false, |v| v.starts_with("number:") || is_number(v))
+}
+
+fn is_arbitrary_length(value: &str) -> bool {
+    match arbitrary(value) {
+        Some(v) if v.starts_with("length:") => true,
+        Some(v) if v.starts_with("calc(") || v.starts_with("clamp(") => true,
+        Some(v) => {
+            let unit = v.trim_start_matches(|c: char| c.is_ascii_digit() || c == '.');
+            unit.len() < v.len()
+                && matches!(
+                    unit,
+                    "" | "px"
+                        | "em"
+                        | "rem"
+                        | "%"
+                        | "vw"
+                        | "vh"
+                        | "ch"
+                        | "ex"
+                        | "pt"
+                        | "pc"
+                        | "in"
+                        | "cm"
+                        | "mm"
+                        | "lh"
+                        | "rlh"
+                        | "svh"
+                        | "lvh"
+                        | "dvh"
+                        | "svw"
+                        | "lvw"
+                        | "dvw"
+                )
+        }
+        None => false,
+    }
+}
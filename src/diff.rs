@@ -0,0 +1,301 @@
+use alloc::{
+    borrow::Cow,
+    fmt::Display,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    normalize::{align, nodes, paths, same_kind, Attr, Node, Step},
+    Document, Element,
+};
+
+/// Compare two element trees node by node
+///
+/// The trees are compared after normalization (see [`Element::eq_normalized`]),
+/// so that only differences affecting the rendered HTML are reported.
+///
+/// The [`Display`] implementation of the returned [`Diff`] lists each change on its own line,
+/// which makes it suitable for assertion messages.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr, elt};
+/// let old = elt::ul([], [elt::li([], ["a".into()]), elt::li([], ["b".into()])]);
+/// let new = elt::ul([attr::id("list")], [elt::li([], ["a".into()]), elt::li([], ["c".into()])]);
+///
+/// let diff = fun_html::diff(&old, &new);
+///
+/// assert_eq!(
+///     diff.to_string(),
+///     "ul: added attribute id=\"list\"\nul > li[2] > #text: changed \"b\" to \"c\"",
+/// );
+/// ```
+pub fn diff(old: &Element, new: &Element) -> Diff {
+    let mut changes = Vec::new();
+    diff_lists(&mut changes, "", &nodes(old), &nodes(new));
+    Diff(changes)
+}
+
+impl Document {
+    /// Compare two documents node by node
+    ///
+    /// See [`diff`]
+    pub fn diff(&self, new: &Document) -> Diff {
+        diff(&self.0, &new.0)
+    }
+}
+
+/// Differences between two element trees
+///
+/// See [`diff`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Diff(Vec<Change>);
+
+impl Diff {
+    /// Returns `true` if there is no difference
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// List of changes, in document order
+    pub fn changes(&self) -> &[Change] {
+        &self.0
+    }
+}
+
+/// A change between two element trees
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// Path of the changed node (e.g. `html > body > ul > li[3]`)
+    ///
+    /// Each segment is the tag of an element (or `#text`, `#raw` and `#script` for other nodes),
+    /// followed by its 1-based position among siblings of the same kind if there are more than one.
+    ///
+    /// Positions refer to the new tree, except for removed nodes.
+    pub path: String,
+    /// What changed
+    pub kind: ChangeKind,
+}
+
+/// Kind of [`Change`]
+///
+/// Nodes are represented by their rendered HTML, and attribute values are `None` for flag attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// The node was added
+    Added(String),
+    /// The node was removed
+    Removed(String),
+    /// The content of a text, raw or script node changed
+    Changed {
+        /// Old content
+        from: String,
+        /// New content
+        to: String,
+    },
+    /// An attribute was added
+    AttributeAdded {
+        /// Attribute name
+        name: String,
+        /// Attribute value
+        value: Option<String>,
+    },
+    /// An attribute was removed
+    AttributeRemoved {
+        /// Attribute name
+        name: String,
+        /// Attribute value
+        value: Option<String>,
+    },
+    /// The value of an attribute changed
+    AttributeChanged {
+        /// Attribute name
+        name: String,
+        /// Old value
+        from: Option<String>,
+        /// New value
+        to: Option<String>,
+    },
+}
+
+impl Display for Diff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "no difference");
+        }
+        for (i, change) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: ", self.path)?;
+        match &self.kind {
+            ChangeKind::Added(node) => write!(f, "added {node}"),
+            ChangeKind::Removed(node) => write!(f, "removed {node}"),
+            ChangeKind::Changed { from, to } => write!(f, "changed {from:?} to {to:?}"),
+            ChangeKind::AttributeAdded { name, value } => {
+                write!(f, "added attribute ")?;
+                write_attribute(f, name, value.as_deref())
+            }
+            ChangeKind::AttributeRemoved { name, value } => {
+                write!(f, "removed attribute ")?;
+                write_attribute(f, name, value.as_deref())
+            }
+            ChangeKind::AttributeChanged { name, from, to } => {
+                write!(f, "changed attribute {name} from ")?;
+                write_value(f, from.as_deref())?;
+                write!(f, " to ")?;
+                write_value(f, to.as_deref())
+            }
+        }
+    }
+}
+
+fn write_attribute(
+    f: &mut core::fmt::Formatter<'_>,
+    name: &str,
+    value: Option<&str>,
+) -> core::fmt::Result {
+    match value {
+        Some(value) => write!(f, "{name}={value:?}"),
+        None => write!(f, "{name}"),
+    }
+}
+
+fn write_value(f: &mut core::fmt::Formatter<'_>, value: Option<&str>) -> core::fmt::Result {
+    match value {
+        Some(value) => write!(f, "{value:?}"),
+        None => write!(f, "(flag)"),
+    }
+}
+
+fn diff_lists(changes: &mut Vec<Change>, parent: &str, old: &[Node<'_>], new: &[Node<'_>]) {
+    let old_paths = paths(parent, old);
+    let new_paths = paths(parent, new);
+
+    for step in align(old, new, match_weight) {
+        match step {
+            Step::Match(i, j) => diff_nodes(changes, &new_paths[j], &old[i], &new[j]),
+            Step::Remove(i) => changes.push(Change {
+                path: old_paths[i].clone(),
+                kind: ChangeKind::Removed(old[i].to_string()),
+            }),
+            Step::Insert(j) => changes.push(Change {
+                path: new_paths[j].clone(),
+                kind: ChangeKind::Added(new[j].to_string()),
+            }),
+        }
+    }
+}
+
+fn diff_nodes(changes: &mut Vec<Change>, path: &str, old: &Node<'_>, new: &Node<'_>) {
+    match (old, new) {
+        (
+            Node::Parent {
+                attributes: old_attributes,
+                children: old_children,
+                ..
+            },
+            Node::Parent {
+                attributes: new_attributes,
+                children: new_children,
+                ..
+            },
+        ) => {
+            diff_attributes(changes, path, old_attributes, new_attributes);
+            diff_lists(changes, path, old_children, new_children);
+        }
+        (
+            Node::Void {
+                attributes: old_attributes,
+                ..
+            },
+            Node::Void {
+                attributes: new_attributes,
+                ..
+            },
+        ) => diff_attributes(changes, path, old_attributes, new_attributes),
+        (Node::Text(from), Node::Text(to)) if from != to => changed(changes, path, from, to),
+        (Node::Script(from), Node::Script(to)) | (Node::Raw(from), Node::Raw(to)) if from != to => {
+            changed(changes, path, from, to);
+        }
+        _ => (),
+    }
+}
+
+fn changed(changes: &mut Vec<Change>, path: &str, from: &str, to: &str) {
+    changes.push(Change {
+        path: path.into(),
+        kind: ChangeKind::Changed {
+            from: from.into(),
+            to: to.into(),
+        },
+    });
+}
+
+/// Attributes are reported by name, and repeated attributes are matched by occurrence
+fn diff_attributes(changes: &mut Vec<Change>, path: &str, old: &[Attr<'_>], new: &[Attr<'_>]) {
+    let mut old = old.to_vec();
+    let mut new = new.to_vec();
+    old.sort_by_key(|(name, _)| *name);
+    new.sort_by_key(|(name, _)| *name);
+    let to_string = |value: &Option<Cow<'_, str>>| value.as_deref().map(String::from);
+    for (i, (name, from)) in old.iter().enumerate() {
+        let occurrence = old[..i].iter().filter(|(n, _)| n == name).count();
+        match new.iter().filter(|(n, _)| n == name).nth(occurrence) {
+            Some((_, to)) if to == from => (),
+            Some((_, to)) => changes.push(Change {
+                path: path.into(),
+                kind: ChangeKind::AttributeChanged {
+                    name: (*name).into(),
+                    from: to_string(from),
+                    to: to_string(to),
+                },
+            }),
+            None => changes.push(Change {
+                path: path.into(),
+                kind: ChangeKind::AttributeRemoved {
+                    name: (*name).into(),
+                    value: to_string(from),
+                },
+            }),
+        }
+    }
+    for (i, (name, value)) in new.iter().enumerate() {
+        let occurrence = new[..i].iter().filter(|(n, _)| n == name).count();
+        if old
+            .iter()
+            .filter(|(n, _)| n == name)
+            .nth(occurrence)
+            .is_none()
+        {
+            changes.push(Change {
+                path: path.into(),
+                kind: ChangeKind::AttributeAdded {
+                    name: (*name).into(),
+                    value: to_string(value),
+                },
+            });
+        }
+    }
+}
+
+/// Nodes of the same kind can be matched, and identical nodes are preferred over merely similar ones
+fn match_weight(old: &Node<'_>, new: &Node<'_>) -> Option<usize> {
+    if !same_kind(old, new) {
+        None
+    } else if old == new {
+        Some(2)
+    } else {
+        Some(1)
+    }
+}
@@ -7,7 +7,7 @@
 use alloc::{borrow::Cow, string::String};
 
 use crate::{
-    attr::{content, href, name, rel},
+    attr::{content, datetime, href, name, rel, HtmlDateTime},
     Attribute, Element, ElementInner,
 };
 
@@ -201,6 +201,257 @@ pub fn small(
     Element::new("small", attributes, children)
 }
 
+/// `<strong>`
+pub fn strong(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("strong", attributes, children)
+}
+
+/// `<em>`
+pub fn em(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("em", attributes, children)
+}
+
+/// `<b>`
+pub fn b(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("b", attributes, children)
+}
+
+/// `<i>`
+pub fn i(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("i", attributes, children)
+}
+
+/// `<u>`
+pub fn u(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("u", attributes, children)
+}
+
+/// `<s>`
+pub fn s(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("s", attributes, children)
+}
+
+/// `<code>`
+pub fn code(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("code", attributes, children)
+}
+
+/// `<kbd>`
+pub fn kbd(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("kbd", attributes, children)
+}
+
+/// `<samp>`
+pub fn samp(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("samp", attributes, children)
+}
+
+/// `<var>`
+pub fn var(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("var", attributes, children)
+}
+
+/// `<mark>`
+pub fn mark(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("mark", attributes, children)
+}
+
+/// `<abbr>`
+///
+/// Use [`attr::title`](crate::attr::title) to provide the expansion of the abbreviation
+pub fn abbr(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("abbr", attributes, children)
+}
+
+/// `<cite>`
+pub fn cite(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("cite", attributes, children)
+}
+
+/// `<q>`
+///
+/// Use [`attr::cite`](crate::attr::cite) to provide the URL of the quoted source
+pub fn q(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("q", attributes, children)
+}
+
+/// `<dfn>`
+pub fn dfn(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("dfn", attributes, children)
+}
+
+/// `<sub>`
+pub fn sub(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("sub", attributes, children)
+}
+
+/// `<sup>`
+pub fn sup(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("sup", attributes, children)
+}
+
+/// `<time>`
+///
+/// Use [`attr::datetime`](crate::attr::datetime) to provide a machine-readable value
+pub fn time(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("time", attributes, children)
+}
+
+/// `<time datetime="{datetime}">`
+///
+/// See [`HtmlDateTime`] for the supported values
+pub fn time_datetime(
+    value: impl Into<HtmlDateTime>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    time([datetime(value)], children)
+}
+
+/// `<data>`
+///
+/// Use [`attr::value`](crate::attr::value) to provide a machine-readable value
+pub fn data(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("data", attributes, children)
+}
+
+/// `<bdi>`
+pub fn bdi(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("bdi", attributes, children)
+}
+
+/// `<bdo>`
+///
+/// Use [`attr::dir`](crate::attr::dir) to specify the direction of the text
+pub fn bdo(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("bdo", attributes, children)
+}
+
+/// `<ruby>`
+pub fn ruby(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("ruby", attributes, children)
+}
+
+/// `<rt>`
+pub fn rt(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("rt", attributes, children)
+}
+
+/// `<rp>`
+pub fn rp(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("rp", attributes, children)
+}
+
+/// `<wbr>`
+pub fn wbr(attributes: impl IntoIterator<Item = Attribute>) -> Element {
+    Element::new_void("wbr", attributes)
+}
+
+/// `<ins>`
+pub fn ins(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("ins", attributes, children)
+}
+
+/// `<del>`
+pub fn del(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("del", attributes, children)
+}
+
+/// `<pre>`
+pub fn pre(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("pre", attributes, children)
+}
+
+/// `<blockquote>`
+///
+/// Use [`attr::cite`](crate::attr::cite) to provide the URL of the quoted source
+pub fn blockquote(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("blockquote", attributes, children)
+}
+
 /// `<span>`
 pub fn span(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -265,6 +516,27 @@ pub fn tfoot(
     Element::new("tfoot", attributes, children)
 }
 
+/// `<caption>`
+pub fn caption(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("caption", attributes, children)
+}
+
+/// `<colgroup>`
+pub fn colgroup(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("colgroup", attributes, children)
+}
+
+/// `<col>`
+pub fn col(attributes: impl IntoIterator<Item = Attribute>) -> Element {
+    Element::new_void("col", attributes)
+}
+
 /// `<section>`
 pub fn section(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -318,6 +590,118 @@ pub fn img(attributes: impl IntoIterator<Item = Attribute>) -> Element {
     Element::new_void("img", attributes)
 }
 
+/// `<picture>`
+///
+/// It should contain [`source`] elements followed by an [`img`] element
+pub fn picture(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("picture", attributes, children)
+}
+
+/// `<figure>`
+pub fn figure(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("figure", attributes, children)
+}
+
+/// `<figcaption>`
+pub fn figcaption(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("figcaption", attributes, children)
+}
+
+/// `<video>`
+///
+/// Children are rendered when the browser does not support the video element, and may contain [`source`] and [`track`] elements
+pub fn video(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("video", attributes, children)
+}
+
+/// `<audio>`
+///
+/// Children are rendered when the browser does not support the audio element, and may contain [`source`] and [`track`] elements
+pub fn audio(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("audio", attributes, children)
+}
+
+/// `<canvas>`
+///
+/// Children are rendered when the browser does not support canvas
+pub fn canvas(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("canvas", attributes, children)
+}
+
+/// `<map>`
+///
+/// Use [`attr::name`](crate::attr::name) to reference it from the `usemap` attribute of an image
+pub fn map(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("map", attributes, children)
+}
+
+/// `<source>`
+pub fn source(attributes: impl IntoIterator<Item = Attribute>) -> Element {
+    Element::new_void("source", attributes)
+}
+
+/// `<track>`
+pub fn track(attributes: impl IntoIterator<Item = Attribute>) -> Element {
+    Element::new_void("track", attributes)
+}
+
+/// `<area>`
+pub fn area(attributes: impl IntoIterator<Item = Attribute>) -> Element {
+    Element::new_void("area", attributes)
+}
+
+/// `<iframe>`
+///
+/// See [`attr::sandbox`](crate::attr::sandbox), [`attr::allow`](crate::attr::allow)
+/// and [`attr::srcdoc`](crate::attr::srcdoc)
+pub fn iframe(attributes: impl IntoIterator<Item = Attribute>) -> Element {
+    Element::new("iframe", attributes, [])
+}
+
+/// `<embed>`
+pub fn embed(attributes: impl IntoIterator<Item = Attribute>) -> Element {
+    Element::new_void("embed", attributes)
+}
+
+/// `<object>`
+///
+/// Children are rendered when the resource cannot be displayed
+pub fn object(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("object", attributes, children)
+}
+
+/// `<noscript>`
+pub fn noscript(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("noscript", attributes, children)
+}
+
 /// `<ul>`
 pub fn ul(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -387,6 +771,50 @@ pub fn option(
     Element::new("option", attributes, children)
 }
 
+/// `<datalist>`
+///
+/// Reference it from an input with [`attr::list`](crate::attr::list)
+pub fn datalist(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("datalist", attributes, children)
+}
+
+/// `<optgroup>`
+///
+/// Use [`attr::label`](crate::attr::label) to name the group
+pub fn optgroup(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("optgroup", attributes, children)
+}
+
+/// `<output>`
+pub fn output(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("output", attributes, children)
+}
+
+/// `<progress>`
+pub fn progress(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("progress", attributes, children)
+}
+
+/// `<meter>`
+pub fn meter(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("meter", attributes, children)
+}
+
 /// `<button>`
 pub fn button(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -395,6 +823,42 @@ pub fn button(
     Element::new("button", attributes, children)
 }
 
+/// `<details>`
+///
+/// Use [`attr::open`](crate::attr::open) to render it expanded, and [`attr::name`](crate::attr::name) to group exclusive accordions
+pub fn details(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("details", attributes, children)
+}
+
+/// `<summary>`
+pub fn summary(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("summary", attributes, children)
+}
+
+/// `<dialog>`
+///
+/// Use [`attr::open`](crate::attr::open) to render it open (non-modal)
+pub fn dialog(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("dialog", attributes, children)
+}
+
+/// `<menu>`
+pub fn menu(
+    attributes: impl IntoIterator<Item = Attribute>,
+    children: impl IntoIterator<Item = Element>,
+) -> Element {
+    Element::new("menu", attributes, children)
+}
+
 /// `<label>`
 pub fn label(
     attributes: impl IntoIterator<Item = Attribute>,
@@ -0,0 +1,151 @@
+use alloc::{borrow::Cow, collections::BTreeSet, fmt::Display, string::String, vec::Vec};
+
+use crate::{
+    normalize::{nodes, visit},
+    Document, Element,
+};
+
+impl Document {
+    /// Check that element ids are unique, and that references to ids point to existing elements
+    ///
+    /// This reports:
+    /// * elements whose `id` is already used by a previous element
+    /// * `label` elements whose `for` attribute does not match any id
+    /// * links whose `href` is a fragment (`#something`) not matching any id
+    ///
+    /// The fragments `#` and `#top` are always valid, as they refer to the top of the document,
+    /// and percent-encoded fragments (e.g. `#caf%C3%A9`) are decoded before being compared to the ids.
+    ///
+    /// It is meant to be used in tests or debug builds, and returns an empty list when no issue is found.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{attr, elt, html, IdIssue, IdIssueKind};
+    /// let doc = html([], [elt::body([], [
+    ///     elt::a([attr::href("#search")], ["Search".into()]),
+    ///     elt::input([attr::id("search")]),
+    ///     elt::input([attr::id("search")]),
+    /// ])]);
+    ///
+    /// assert_eq!(
+    ///     doc.check_ids(),
+    ///     [IdIssue {
+    ///         path: "html > body > input[2]".into(),
+    ///         kind: IdIssueKind::DuplicateId("search".into()),
+    ///     }],
+    /// );
+    /// ```
+    pub fn check_ids(&self) -> Vec<IdIssue> {
+        self.0.check_ids()
+    }
+}
+
+impl Element {
+    /// Check that element ids are unique, and that references to ids point to existing elements
+    ///
+    /// See [`Document::check_ids`]
+    pub fn check_ids(&self) -> Vec<IdIssue> {
+        let nodes = nodes(self);
+        let mut ids = BTreeSet::new();
+        let mut issues = Vec::new();
+        visit(&nodes, "", &mut |path, node| {
+            if let Some(id) = node.attribute("id") {
+                if !ids.insert(id) {
+                    issues.push(IdIssue {
+                        path: path.into(),
+                        kind: IdIssueKind::DuplicateId(id.into()),
+                    });
+                }
+            }
+        });
+        visit(&nodes, "", &mut |path, node| {
+            let target = node
+                .attribute("for")
+                .filter(|_| node.tag() == Some("label"));
+            if let Some(target) = target.filter(|target| !ids.contains(*target)) {
+                issues.push(IdIssue {
+                    path: path.into(),
+                    kind: IdIssueKind::UnknownLabelTarget(target.into()),
+                });
+            }
+            let fragment = node
+                .attribute("href")
+                .and_then(|href| href.strip_prefix('#'));
+            if let Some(fragment) = fragment.filter(|fragment| {
+                !fragment.is_empty()
+                    && !fragment.eq_ignore_ascii_case("top")
+                    && !ids.contains(fragment)
+                    && !ids.contains(&*percent_decode(fragment))
+            }) {
+                issues.push(IdIssue {
+                    path: path.into(),
+                    kind: IdIssueKind::BrokenFragmentLink(fragment.into()),
+                });
+            }
+        });
+        issues
+    }
+}
+
+/// Decode the percent-encoded bytes of a fragment (kept as is if the result is not valid UTF-8)
+fn percent_decode(fragment: &str) -> Cow<'_, str> {
+    if !fragment.contains('%') {
+        return Cow::Borrowed(fragment);
+    }
+    let bytes = fragment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| core::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).map_or(Cow::Borrowed(fragment), Cow::Owned)
+}
+
+/// An issue found by [`Document::check_ids`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdIssue {
+    /// Path of the offending element (e.g. `html > body > input[2]`)
+    pub path: String,
+    /// What is wrong
+    pub kind: IdIssueKind,
+}
+
+/// Kind of [`IdIssue`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdIssueKind {
+    /// The id is already used by a previous element
+    DuplicateId(String),
+    /// The `for` attribute of a `label` does not match any id
+    UnknownLabelTarget(String),
+    /// The fragment of an `href` does not match any id
+    BrokenFragmentLink(String),
+}
+
+impl Display for IdIssue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.kind {
+            IdIssueKind::DuplicateId(id) => write!(f, "{}: duplicate id {id:?}", self.path),
+            IdIssueKind::UnknownLabelTarget(id) => {
+                write!(f, "{}: label for unknown id {id:?}", self.path)
+            }
+            IdIssueKind::BrokenFragmentLink(id) => {
+                write!(f, "{}: link to unknown fragment {id:?}", self.path)
+            }
+        }
+    }
+}
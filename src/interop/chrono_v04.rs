@@ -0,0 +1,56 @@
+use chrono_v04::{
+    DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Timelike,
+};
+
+use crate::attr::HtmlDateTime;
+
+fn date(date: &impl Datelike) -> (i32, u8, u8) {
+    (date.year(), date.month() as u8, date.day() as u8)
+}
+
+fn time(time: &impl Timelike) -> (u8, u8, u8, u16) {
+    (
+        time.hour() as u8,
+        time.minute() as u8,
+        time.second() as u8,
+        (time.nanosecond() % 1_000_000_000 / 1_000_000) as u16,
+    )
+}
+
+impl From<NaiveDate> for HtmlDateTime {
+    fn from(value: NaiveDate) -> Self {
+        let (year, month, day) = date(&value);
+        Self::date(year, month, day)
+    }
+}
+
+impl From<NaiveTime> for HtmlDateTime {
+    fn from(value: NaiveTime) -> Self {
+        let (hour, minute, second, millis) = time(&value);
+        Self::time(hour, minute, second, millis)
+    }
+}
+
+impl From<NaiveDateTime> for HtmlDateTime {
+    fn from(value: NaiveDateTime) -> Self {
+        Self::local(date(&value), time(&value))
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for HtmlDateTime {
+    fn from(value: DateTime<Tz>) -> Self {
+        let offset = value.offset().fix().local_minus_utc();
+        if offset % 60 != 0 {
+            let utc = value.naive_utc();
+            return Self::global(date(&utc), time(&utc), 0);
+        }
+        let local = value.naive_local();
+        Self::global(date(&local), time(&local), offset)
+    }
+}
+
+impl From<chrono_v04::Duration> for HtmlDateTime {
+    fn from(value: chrono_v04::Duration) -> Self {
+        Self::signed_duration(value.num_seconds(), value.subsec_nanos())
+    }
+}
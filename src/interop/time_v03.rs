@@ -0,0 +1,56 @@
+use time_v03::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::attr::HtmlDateTime;
+
+fn date(date: Date) -> (i32, u8, u8) {
+    (date.year(), date.month().into(), date.day())
+}
+
+fn time(time: Time) -> (u8, u8, u8, u16) {
+    (
+        time.hour(),
+        time.minute(),
+        time.second(),
+        time.millisecond(),
+    )
+}
+
+impl From<Date> for HtmlDateTime {
+    fn from(value: Date) -> Self {
+        let (year, month, day) = date(value);
+        Self::date(year, month, day)
+    }
+}
+
+impl From<Time> for HtmlDateTime {
+    fn from(value: Time) -> Self {
+        let (hour, minute, second, millis) = time(value);
+        Self::time(hour, minute, second, millis)
+    }
+}
+
+impl From<PrimitiveDateTime> for HtmlDateTime {
+    fn from(value: PrimitiveDateTime) -> Self {
+        Self::local(date(value.date()), time(value.time()))
+    }
+}
+
+impl From<OffsetDateTime> for HtmlDateTime {
+    fn from(value: OffsetDateTime) -> Self {
+        let value = match value.offset().seconds_past_minute() {
+            0 => value,
+            _ => value.checked_to_offset(UtcOffset::UTC).unwrap_or(value),
+        };
+        Self::global(
+            date(value.date()),
+            time(value.time()),
+            value.offset().whole_seconds(),
+        )
+    }
+}
+
+impl From<Duration> for HtmlDateTime {
+    fn from(value: Duration) -> Self {
+        Self::signed_duration(value.whole_seconds(), value.subsec_nanoseconds())
+    }
+}
@@ -0,0 +1,17 @@
+use alloc::string::String;
+
+use url_v2::Url;
+
+use crate::attr::Href;
+
+impl From<Url> for Href {
+    fn from(value: Url) -> Self {
+        String::from(value).into()
+    }
+}
+
+impl From<&Url> for Href {
+    fn from(value: &Url) -> Self {
+        String::from(value.as_str()).into()
+    }
+}
@@ -61,11 +61,25 @@
 //! * `salvo_v074`: implements the [`Scribe` trait from salvo 0.74](https://docs.rs/salvo/0.74/salvo/trait.Scribe.html) for [`Document`] and [`Element`]
 //! * `salvo_v076`: implements the [`Scribe` trait from salvo 0.76](https://docs.rs/salvo/0.76/salvo/trait.Scribe.html) for [`Document`] and [`Element`]
 //! * `maud_v026`: implements `Render` add provide conversion from/to `Markup` for [maud 0.26](https://docs.rs/maud/0.26)
+//! * `chrono_v04`: conversions from the date, time and duration types of [chrono 0.4](https://docs.rs/chrono/0.4) to [`attr::HtmlDateTime`]
+//! * `time_v03`: conversions from the date, time and duration types of [time 0.3](https://docs.rs/time/0.3) to [`attr::HtmlDateTime`]
+//! * `url_v2`: conversion from [`Url` of url 2](https://docs.rs/url/2/url/struct.Url.html) to [`attr::Href`]
+//! * `tailwind`: resolves conflicting [Tailwind](https://tailwindcss.com) utilities with `attr::class_tw` and `ClassList::tailwind` (see [`attr::tailwind`])
 
+pub mod a11y;
 pub mod attr;
 pub mod elt;
+pub mod validate;
+
+mod diff;
+mod ids;
+mod normalize;
+mod patch;
+mod value;
 
 mod interop {
+    #[cfg(feature = "chrono_v04")]
+    mod chrono_v04;
     #[cfg(feature = "maud_v026")]
     mod maud_v026;
     #[cfg(feature = "rocket_v05")]
@@ -74,10 +88,19 @@ mod interop {
     mod salvo_v074;
     #[cfg(feature = "salvo_v076")]
     mod salvo_v076;
+    #[cfg(feature = "time_v03")]
+    mod time_v03;
+    #[cfg(feature = "url_v2")]
+    mod url_v2;
 }
 
 extern crate alloc;
 
+pub use diff::{diff, Change, ChangeKind, Diff};
+pub use ids::{IdIssue, IdIssueKind};
+pub use patch::{patch, Patch, Patches};
+pub use value::Number;
+
 use alloc::{borrow::Cow, fmt::Display, vec::Vec};
 
 /// An HTML document (`<!DOCTYPE html>`)
@@ -90,7 +113,7 @@ use alloc::{borrow::Cow, fmt::Display, vec::Vec};
 ///
 /// assert_eq!(doc.to_string(), "<!DOCTYPE html>\n<html><head></head><body></body></html>");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Document(Element);
 
 /// An HTML element
@@ -105,10 +128,10 @@ pub struct Document(Element);
 ///
 /// assert_eq!(element.to_string(), "<div></div>");
 /// ```    
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Element(ElementInner);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum ElementInner {
     Parent {
         tag: &'static str,
@@ -148,13 +171,14 @@ impl Default for ElementInner {
 ///   r#"id="foo""#,
 /// )
 /// ```    
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Attribute(AttributeInner);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum AttributeInner {
     KeyValue(Cow<'static, str>, Cow<'static, str>),
-    KeyValueInt(Cow<'static, str>, i32),
+    KeyValueNumber(Cow<'static, str>, Number),
+    KeyValueDisplay(Cow<'static, str>, value::LazyValue),
     Flag(Cow<'static, str>),
     None,
 }
@@ -183,6 +207,9 @@ impl Display for Document {
 
 impl Element {
     /// Create a new HTML element from its tag, attributes, and children
+    ///
+    /// Repeated `class` attributes are merged into the first one (without duplicates),
+    /// and so are the declarations of repeated `style` attributes.
     pub fn new(
         tag: &'static str,
         attributes: impl IntoIterator<Item = Attribute>,
@@ -191,7 +218,7 @@ impl Element {
         assert_valid_tag_name(tag);
         Self(ElementInner::Parent {
             tag,
-            attributes: attributes.into_iter().map(Into::into).collect(),
+            attributes: normalize::merge_repeated_attributes(attributes.into_iter().collect()),
             children: children.into_iter().collect(),
         })
     }
@@ -200,12 +227,15 @@ impl Element {
     ///
     /// ("void" element cannot have children and do not need a closing tag)
     ///
+    /// Repeated `class` attributes are merged into the first one (without duplicates),
+    /// and so are the declarations of repeated `style` attributes.
+    ///
     /// [void]: https://developer.mozilla.org/en-US/docs/Glossary/Void_element
     pub fn new_void(tag: &'static str, attributes: impl IntoIterator<Item = Attribute>) -> Self {
         assert_valid_tag_name(tag);
         Self(ElementInner::Void {
             tag,
-            attributes: attributes.into_iter().collect(),
+            attributes: normalize::merge_repeated_attributes(attributes.into_iter().collect()),
         })
     }
 }
@@ -282,9 +312,14 @@ impl Display for Attribute {
                     html_escape::encode_double_quoted_attribute(&value)
                 )
             }
-            AttributeInner::KeyValueInt(key, value) => {
+            AttributeInner::KeyValueNumber(key, value) => {
                 write!(f, "{key}=\"{value}\"")
             }
+            AttributeInner::KeyValueDisplay(key, value) => {
+                write!(f, "{key}=\"")?;
+                value.write_escaped(f)?;
+                write!(f, "\"")
+            }
             AttributeInner::Flag(key) => write!(f, "{key}"),
             AttributeInner::None => Ok(()),
         }
@@ -300,8 +335,60 @@ impl Attribute {
 
     /// Create attribute with an integer value
     pub fn new_int(name: &'static str, value: i32) -> Self {
+        Self::new_number(name, value)
+    }
+
+    /// Create attribute with a numeric value (of any integer type, `f32` or `f64`)
+    ///
+    /// See [`Number`]
+    ///
+    /// The value must not be a NaN or an infinite float, as they are not valid in HTML,
+    /// which is checked in debug builds
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::Attribute;
+    /// assert_eq!(Attribute::new_number("step", 0.01).to_string(), r#"step="0.01""#);
+    /// assert_eq!(Attribute::new_number("max", u64::MAX).to_string(), r#"max="18446744073709551615""#);
+    /// ```
+    pub fn new_number(name: &'static str, value: impl Into<Number>) -> Self {
+        assert_valid_attribute_name(name);
+        let value = value.into();
+        debug_assert!(
+            value.is_finite(),
+            "invalid number for attribute {name}: {value}"
+        );
+        Self(AttributeInner::KeyValueNumber(name.into(), value))
+    }
+
+    /// Create attribute whose value is formatted with [`Display`] when rendered
+    ///
+    /// The formatted value is escaped, and no intermediate `String` is allocated when rendering.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::Attribute;
+    /// struct Coordinates(i32, i32);
+    ///
+    /// impl core::fmt::Display for Coordinates {
+    ///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    ///         write!(f, "{},{}", self.0, self.1)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Attribute::new_display("data-position", Coordinates(3, -2)).to_string(),
+    ///     r#"data-position="3,-2""#,
+    /// );
+    /// ```
+    pub fn new_display(name: &'static str, value: impl Display + Send + Sync + 'static) -> Self {
         assert_valid_attribute_name(name);
-        Self(AttributeInner::KeyValueInt(name.into(), value))
+        Self(AttributeInner::KeyValueDisplay(
+            name.into(),
+            value::LazyValue::new(value),
+        ))
     }
 
     /// Create a new flag attribute (that doesn't take a value)
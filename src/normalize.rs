@@ -0,0 +1,532 @@
+use alloc::{
+    borrow::Cow,
+    fmt::Display,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{attr::css, Attribute, AttributeInner, Document, Element, ElementInner};
+
+impl Document {
+    /// Compare two documents, ignoring differences that do not affect their meaning
+    ///
+    /// See [`Element::eq_normalized`]
+    pub fn eq_normalized(&self, other: &Document) -> bool {
+        self.0.eq_normalized(&other.0)
+    }
+
+    /// Simplify the document tree without changing its meaning
+    ///
+    /// See [`Element::normalize`]
+    pub fn normalize(self) -> Self {
+        Self(self.0.normalize())
+    }
+}
+
+impl Element {
+    /// Compare two elements, ignoring differences that do not affect their meaning
+    ///
+    /// Unlike `==`, which compares the trees structurally, this:
+    /// * ignores the order of attributes
+    /// * flattens nested groups of elements
+    /// * ignores [`elt::none`](crate::elt::none) and [`attr::none`](crate::attr::none)
+    /// * compares integer attribute values by their textual representation
+    /// * merges adjacent text nodes
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{attr, elt, Attribute};
+    /// let a = elt::div(
+    ///     [attr::id("foo"), Attribute::new_int("tabindex", 5)],
+    ///     [elt::text("hello "), elt::none(), elt::text("world")],
+    /// );
+    /// let b = elt::div(
+    ///     [("tabindex", "5").into(), attr::id("foo")],
+    ///     [elt::text("hello world")],
+    /// );
+    ///
+    /// assert_ne!(a, b);
+    /// assert!(a.eq_normalized(&b));
+    /// ```
+    pub fn eq_normalized(&self, other: &Element) -> bool {
+        nodes(self) == nodes(other)
+    }
+
+    /// Simplify the element tree without changing its meaning
+    ///
+    /// This:
+    /// * flattens nested groups of elements
+    /// * removes [`elt::none`](crate::elt::none) elements and [`attr::none`](crate::attr::none) attributes
+    /// * removes empty text nodes and merges adjacent ones
+    /// * collapses attributes with the same name
+    ///
+    /// When an attribute is repeated, it keeps the position of its first occurrence and the value of the last one,
+    /// except for `class`, whose values are merged (without duplicates),
+    /// and `style`, whose declarations are merged (later declarations override earlier ones, unless `!important`).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use fun_html::{attr, elt};
+    /// let element = elt::div(
+    ///     [attr::id("a"), attr::class(["foo"]), attr::none(), attr::id("b"), attr::class(["bar", "foo"])],
+    ///     [elt::text("hello "), [elt::none(), elt::text("world")].into()],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     element.normalize(),
+    ///     elt::div([attr::id("b"), attr::class(["foo", "bar"])], [elt::text("hello world")]),
+    /// );
+    /// ```
+    pub fn normalize(self) -> Self {
+        let mut elements = Vec::new();
+        push_normalized(&mut elements, self);
+        match elements.len() {
+            0 => ElementInner::None.into(),
+            1 => elements.remove(0),
+            _ => ElementInner::Multiple(elements).into(),
+        }
+    }
+}
+
+fn push_normalized(elements: &mut Vec<Element>, element: Element) {
+    match element.0 {
+        ElementInner::Parent {
+            tag,
+            attributes,
+            children,
+        } => {
+            let mut normalized_children = Vec::new();
+            for child in children {
+                push_normalized(&mut normalized_children, child);
+            }
+            elements.push(
+                ElementInner::Parent {
+                    tag,
+                    attributes: normalize_attributes(attributes),
+                    children: normalized_children,
+                }
+                .into(),
+            );
+        }
+        ElementInner::Void { tag, attributes } => elements.push(
+            ElementInner::Void {
+                tag,
+                attributes: normalize_attributes(attributes),
+            }
+            .into(),
+        ),
+        ElementInner::Text(text) if text.is_empty() => (),
+        ElementInner::Text(text) => match elements.last_mut() {
+            Some(Element(ElementInner::Text(previous))) => previous.to_mut().push_str(&text),
+            _ => elements.push(ElementInner::Text(text).into()),
+        },
+        ElementInner::Multiple(children) => {
+            for child in children {
+                push_normalized(elements, child);
+            }
+        }
+        ElementInner::None => (),
+        inner @ (ElementInner::Script(_) | ElementInner::Raw(_)) => elements.push(inner.into()),
+    }
+}
+
+fn normalize_attributes(attributes: Vec<Attribute>) -> Vec<Attribute> {
+    let mut normalized: Vec<Attribute> = Vec::with_capacity(attributes.len());
+    for attribute in attributes {
+        if matches!(attribute.0, AttributeInner::None) {
+            continue;
+        }
+        let existing = normalized
+            .iter()
+            .position(|a| attribute_name(a) == attribute_name(&attribute));
+        match existing {
+            Some(i) if attribute_name(&attribute) == Some("class") => {
+                normalized[i] = merge_classes(&normalized[i], &attribute);
+            }
+            Some(i) if attribute_name(&attribute) == Some("style") => {
+                normalized[i] = merge_styles(&normalized[i], &attribute);
+            }
+            Some(i) => normalized[i] = attribute,
+            None => normalized.push(attribute),
+        }
+    }
+    normalized
+}
+
+/// Merge the repeated `class` and `style` attributes of an element into the first one
+///
+/// Browsers only read the first occurrence of an attribute, so the others would otherwise be lost.
+pub(crate) fn merge_repeated_attributes(attributes: Vec<Attribute>) -> Vec<Attribute> {
+    let is_repeated = |name| {
+        attributes
+            .iter()
+            .filter(|a| attribute_name(a) == Some(name))
+            .count()
+            > 1
+    };
+    if !is_repeated("class") && !is_repeated("style") {
+        return attributes;
+    }
+    let mut merged: Vec<Attribute> = Vec::with_capacity(attributes.len());
+    for attribute in attributes {
+        let existing = match attribute_name(&attribute) {
+            Some(name @ ("class" | "style")) => {
+                merged.iter().position(|a| attribute_name(a) == Some(name))
+            }
+            _ => None,
+        };
+        match existing {
+            Some(i) if attribute_name(&attribute) == Some("class") => {
+                merged[i] = merge_classes(&merged[i], &attribute);
+            }
+            Some(i) => merged[i] = merge_styles(&merged[i], &attribute),
+            None => merged.push(attribute),
+        }
+    }
+    merged
+}
+
+fn attribute_name(attribute: &Attribute) -> Option<&str> {
+    match &attribute.0 {
+        AttributeInner::KeyValue(key, _)
+        | AttributeInner::KeyValueNumber(key, _)
+        | AttributeInner::KeyValueDisplay(key, _)
+        | AttributeInner::Flag(key) => Some(key),
+        AttributeInner::None => None,
+    }
+}
+
+fn merge_classes(a: &Attribute, b: &Attribute) -> Attribute {
+    let values: Vec<Cow<'_, str>> = [a, b].into_iter().filter_map(attribute_value).collect();
+    let mut classes = String::new();
+    for class in values.iter().flat_map(|value| value.split_whitespace()) {
+        if !classes.split(' ').any(|c| c == class) {
+            if !classes.is_empty() {
+                classes.push(' ');
+            }
+            classes.push_str(class);
+        }
+    }
+    Attribute::new("class", classes)
+}
+
+fn merge_styles(a: &Attribute, b: &Attribute) -> Attribute {
+    let a = attribute_value(a).unwrap_or_default();
+    let b = attribute_value(b).unwrap_or_default();
+    Attribute::new("style", css::merge(&a, &b))
+}
+
+fn attribute_value(attribute: &Attribute) -> Option<Cow<'_, str>> {
+    match &attribute.0 {
+        AttributeInner::KeyValue(_, value) => Some(Cow::Borrowed(value)),
+        AttributeInner::KeyValueNumber(_, value) => Some(Cow::Owned(value.to_string())),
+        AttributeInner::KeyValueDisplay(_, value) => Some(Cow::Owned(value.to_string())),
+        AttributeInner::Flag(_) | AttributeInner::None => None,
+    }
+}
+
+/// Normalized view of an element tree, used for comparisons
+///
+/// Attributes are kept in source order, and compared regardless of their order
+#[derive(Debug)]
+pub(crate) enum Node<'a> {
+    Parent {
+        tag: &'static str,
+        attributes: Vec<Attr<'a>>,
+        children: Vec<Node<'a>>,
+    },
+    Void {
+        tag: &'static str,
+        attributes: Vec<Attr<'a>>,
+    },
+    Text(Cow<'a, str>),
+    Script(&'a str),
+    Raw(&'a str),
+}
+
+/// Attribute name and value (`None` for flags)
+pub(crate) type Attr<'a> = (&'a str, Option<Cow<'a, str>>);
+
+pub(crate) fn nodes(element: &Element) -> Vec<Node<'_>> {
+    let mut nodes = Vec::new();
+    push_nodes(&mut nodes, element);
+    nodes
+}
+
+fn push_nodes<'a>(nodes: &mut Vec<Node<'a>>, element: &'a Element) {
+    match &element.0 {
+        ElementInner::Parent {
+            tag,
+            attributes,
+            children,
+        } => {
+            let mut child_nodes = Vec::new();
+            for child in children {
+                push_nodes(&mut child_nodes, child);
+            }
+            nodes.push(Node::Parent {
+                tag,
+                attributes: attrs(attributes),
+                children: child_nodes,
+            });
+        }
+        ElementInner::Void { tag, attributes } => nodes.push(Node::Void {
+            tag,
+            attributes: attrs(attributes),
+        }),
+        ElementInner::Text(text) if text.is_empty() => (),
+        ElementInner::Text(text) => match nodes.last_mut() {
+            Some(Node::Text(previous)) => previous.to_mut().push_str(text),
+            _ => nodes.push(Node::Text(Cow::Borrowed(text))),
+        },
+        ElementInner::Script(script) => nodes.push(Node::Script(script)),
+        ElementInner::Raw(raw) => nodes.push(Node::Raw(raw)),
+        ElementInner::Multiple(elements) => {
+            for element in elements {
+                push_nodes(nodes, element);
+            }
+        }
+        ElementInner::None => (),
+    }
+}
+
+fn attrs(attributes: &[Attribute]) -> Vec<Attr<'_>> {
+    attributes
+        .iter()
+        .filter_map(|attribute| match &attribute.0 {
+            AttributeInner::KeyValue(key, value) => Some((&**key, Some(Cow::Borrowed(&**value)))),
+            AttributeInner::KeyValueNumber(key, value) => {
+                Some((&**key, Some(Cow::Owned(value.to_string()))))
+            }
+            AttributeInner::KeyValueDisplay(key, value) => {
+                Some((&**key, Some(Cow::Owned(value.to_string()))))
+            }
+            AttributeInner::Flag(key) => Some((&**key, None)),
+            AttributeInner::None => None,
+        })
+        .collect()
+}
+
+impl PartialEq for Node<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Node::Parent {
+                    tag,
+                    attributes,
+                    children,
+                },
+                Node::Parent {
+                    tag: other_tag,
+                    attributes: other_attributes,
+                    children: other_children,
+                },
+            ) => {
+                tag == other_tag
+                    && same_attributes(attributes, other_attributes)
+                    && children == other_children
+            }
+            (
+                Node::Void { tag, attributes },
+                Node::Void {
+                    tag: other_tag,
+                    attributes: other_attributes,
+                },
+            ) => tag == other_tag && same_attributes(attributes, other_attributes),
+            (Node::Text(a), Node::Text(b)) => a == b,
+            (Node::Script(a), Node::Script(b)) | (Node::Raw(a), Node::Raw(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// Compare attributes regardless of their order
+fn same_attributes(a: &[Attr<'_>], b: &[Attr<'_>]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    a.sort();
+    b.sort();
+    a == b
+}
+
+/// Returns `true` if both nodes are elements with the same tag, or both are text, script or raw nodes
+pub(crate) fn same_kind(a: &Node<'_>, b: &Node<'_>) -> bool {
+    match (a, b) {
+        (Node::Parent { tag: a, .. }, Node::Parent { tag: b, .. })
+        | (Node::Void { tag: a, .. }, Node::Void { tag: b, .. }) => a == b,
+        (Node::Text(_), Node::Text(_))
+        | (Node::Script(_), Node::Script(_))
+        | (Node::Raw(_), Node::Raw(_)) => true,
+        _ => false,
+    }
+}
+
+/// Visit the nodes and their descendants in document order, with their path
+pub(crate) fn visit<'n, 'a>(
+    nodes: &'n [Node<'a>],
+    parent: &str,
+    visitor: &mut impl FnMut(&str, &'n Node<'a>),
+) {
+    for (path, node) in paths(parent, nodes).iter().zip(nodes) {
+        visitor(path, node);
+        if let Node::Parent { children, .. } = node {
+            visit(children, path, visitor);
+        }
+    }
+}
+
+/// Path of each node (e.g. `html > body > ul > li[3]`)
+///
+/// Each segment is the tag of an element (or `#text`, `#raw` and `#script` for other nodes),
+/// followed by its 1-based position among siblings of the same kind if there are more than one.
+pub(crate) fn paths(parent: &str, nodes: &[Node<'_>]) -> Vec<String> {
+    let labels: Vec<&str> = nodes.iter().map(Node::label).collect();
+    labels
+        .iter()
+        .enumerate()
+        .map(|(i, label)| {
+            let separator = if parent.is_empty() { "" } else { " > " };
+            if labels.iter().filter(|l| *l == label).count() > 1 {
+                let position = labels[..i].iter().filter(|l| *l == label).count() + 1;
+                format!("{parent}{separator}{label}[{position}]")
+            } else {
+                format!("{parent}{separator}{label}")
+            }
+        })
+        .collect()
+}
+
+impl<'a> Node<'a> {
+    fn label(&self) -> &str {
+        match self {
+            Node::Parent { tag, .. } | Node::Void { tag, .. } => tag,
+            Node::Text(_) => "#text",
+            Node::Script(_) => "#script",
+            Node::Raw(_) => "#raw",
+        }
+    }
+
+    /// Tag of the element, or `None` if this is not an element
+    pub(crate) fn tag(&self) -> Option<&'static str> {
+        match self {
+            Node::Parent { tag, .. } | Node::Void { tag, .. } => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// Value of the first attribute with this name (empty for flags)
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        match self {
+            Node::Parent { attributes, .. } | Node::Void { attributes, .. } => attributes
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, value)| value.as_deref().unwrap_or_default()),
+            _ => None,
+        }
+    }
+}
+
+/// Step of an alignment between two lists of nodes
+pub(crate) enum Step {
+    /// The old node at the first index corresponds to the new node at the second index
+    Match(usize, usize),
+    /// The old node at this index has no counterpart
+    Remove(usize),
+    /// The new node at this index has no counterpart
+    Insert(usize),
+}
+
+/// Align two lists of nodes using a weighted longest common subsequence
+///
+/// `weight` returns how desirable it is to match two nodes, or `None` if they cannot be matched.
+/// When a node is removed and another inserted at the same place, the removal comes first.
+pub(crate) fn align(
+    old: &[Node<'_>],
+    new: &[Node<'_>],
+    weight: impl Fn(&Node<'_>, &Node<'_>) -> Option<usize>,
+) -> Vec<Step> {
+    let mut score = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            score[i][j] = score[i + 1][j].max(score[i][j + 1]);
+            if let Some(weight) = weight(&old[i], &new[j]) {
+                score[i][j] = score[i][j].max(score[i + 1][j + 1] + weight);
+            }
+        }
+    }
+
+    let mut steps = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        let matched = if i < old.len() && j < new.len() {
+            weight(&old[i], &new[j])
+        } else {
+            None
+        };
+        match matched {
+            Some(weight) if score[i][j] == score[i + 1][j + 1] + weight => {
+                steps.push(Step::Match(i, j));
+                i += 1;
+                j += 1;
+            }
+            _ if i < old.len() && (j == new.len() || score[i + 1][j] >= score[i][j + 1]) => {
+                steps.push(Step::Remove(i));
+                i += 1;
+            }
+            _ => {
+                steps.push(Step::Insert(j));
+                j += 1;
+            }
+        }
+    }
+    steps
+}
+
+impl Display for Node<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Node::Parent {
+                tag,
+                attributes,
+                children,
+            } => {
+                write!(f, "<{tag}")?;
+                write_attrs(f, attributes)?;
+                write!(f, ">")?;
+                for child in children {
+                    write!(f, "{child}")?;
+                }
+                write!(f, "</{tag}>")
+            }
+            Node::Void { tag, attributes } => {
+                write!(f, "<{tag}")?;
+                write_attrs(f, attributes)?;
+                write!(f, ">")
+            }
+            Node::Text(text) => write!(f, "{}", html_escape::encode_text(text)),
+            Node::Script(script) => write!(f, "{}", html_escape::encode_script(script)),
+            Node::Raw(raw) => write!(f, "{raw}"),
+        }
+    }
+}
+
+fn write_attrs(f: &mut core::fmt::Formatter<'_>, attributes: &[Attr<'_>]) -> core::fmt::Result {
+    for (key, value) in attributes {
+        match value {
+            Some(value) => write!(
+                f,
+                " {key}=\"{}\"",
+                html_escape::encode_double_quoted_attribute(value)
+            )?,
+            None => write!(f, " {key}")?,
+        }
+    }
+    Ok(())
+}
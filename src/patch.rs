@@ -0,0 +1,349 @@
+use alloc::{
+    borrow::Cow,
+    fmt::Write,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    normalize::{align, nodes, same_kind, Attr, Node, Step},
+    Element,
+};
+
+/// Compute the DOM operations turning the rendering of `old` into the rendering of `new`
+///
+/// The trees are compared after normalization (see [`Element::eq_normalized`]).
+/// Sibling elements having an `id` or a `data-key` attribute are matched by key,
+/// other nodes are matched by position.
+///
+/// Paths are lists of child node indices, starting from the element containing the rendered HTML.
+/// Operations must be applied in order, as indices account for the previous operations.
+///
+/// Raw HTML (see [`elt::raw`](crate::elt::raw)) is assumed to render as a single DOM node.
+///
+/// See [`Patches::to_json`] for the serialization format.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr, elt, Patch};
+/// let old = elt::ul([], [elt::li([attr::id("a")], ["a".into()])]);
+/// let new = elt::ul([], [
+///     elt::li([attr::id("b")], ["b".into()]),
+///     elt::li([attr::id("a")], ["a".into()]),
+/// ]);
+///
+/// let patches = fun_html::patch(&old, &new);
+///
+/// assert_eq!(
+///     patches.operations(),
+///     [Patch::InsertChild { path: vec![0], index: 0, html: r#"<li id="b">b</li>"#.into() }],
+/// );
+/// assert_eq!(patches.to_json(), r#"[["i",[0],0,"<li id=\"b\">b</li>"]]"#);
+/// ```
+pub fn patch(old: &Element, new: &Element) -> Patches {
+    let mut patches = Vec::new();
+    let mut path = Vec::new();
+    patch_children(&mut patches, &mut path, &nodes(old), &nodes(new));
+    Patches(patches)
+}
+
+/// List of DOM operations
+///
+/// See [`patch`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Patches(Vec<Patch>);
+
+/// A DOM operation
+///
+/// See [`patch`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Patch {
+    /// Replace the node at `path` with `html`
+    Replace {
+        /// Path of the node to replace
+        path: Vec<usize>,
+        /// HTML of the new node
+        html: String,
+    },
+    /// Set an attribute of the element at `path`
+    SetAttribute {
+        /// Path of the element
+        path: Vec<usize>,
+        /// Attribute name
+        name: String,
+        /// Attribute value (empty for flag attributes)
+        value: String,
+    },
+    /// Remove an attribute of the element at `path`
+    RemoveAttribute {
+        /// Path of the element
+        path: Vec<usize>,
+        /// Attribute name
+        name: String,
+    },
+    /// Insert `html` as child of the node at `path`, at `index`
+    InsertChild {
+        /// Path of the parent node
+        path: Vec<usize>,
+        /// Index of the new child node
+        index: usize,
+        /// HTML of the new child node
+        html: String,
+    },
+    /// Remove the child at `index` of the node at `path`
+    RemoveChild {
+        /// Path of the parent node
+        path: Vec<usize>,
+        /// Index of the child node to remove
+        index: usize,
+    },
+}
+
+impl Patches {
+    /// Returns `true` if there is no operation to apply
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// List of operations, in the order they must be applied
+    pub fn operations(&self) -> &[Patch] {
+        &self.0
+    }
+
+    /// Serialize the operations as a compact JSON array
+    ///
+    /// Each operation is an array whose first item is the operation code:
+    ///
+    /// | Operation                    | JSON                               |
+    /// |------------------------------|------------------------------------|
+    /// | [`Patch::Replace`]           | `["r", path, html]`                |
+    /// | [`Patch::SetAttribute`]      | `["s", path, name, value]`         |
+    /// | [`Patch::RemoveAttribute`]   | `["u", path, name]`                |
+    /// | [`Patch::InsertChild`]       | `["i", path, index, html]`         |
+    /// | [`Patch::RemoveChild`]       | `["d", path, index]`               |
+    ///
+    /// Where `path` is an array of child node indices.
+    ///
+    /// Here is an example of script applying the operations to the children of `root`:
+    ///
+    /// ```js
+    /// function applyPatches(root, patches) {
+    ///   const node = (path) => path.reduce((n, i) => n.childNodes[i], root);
+    ///   const parse = (html) => {
+    ///     const template = document.createElement("template");
+    ///     template.innerHTML = html;
+    ///     return template.content.firstChild ?? document.createTextNode("");
+    ///   };
+    ///   for (const [op, path, a, b] of patches) {
+    ///     const target = node(path);
+    ///     switch (op) {
+    ///       case "r": target.replaceWith(parse(a)); break;
+    ///       case "s": target.setAttribute(a, b); break;
+    ///       case "u": target.removeAttribute(a); break;
+    ///       case "i": target.insertBefore(parse(b), target.childNodes[a] ?? null); break;
+    ///       case "d": target.childNodes[a].remove(); break;
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("[");
+        for (i, patch) in self.0.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            match patch {
+                Patch::Replace { path, html } => {
+                    json.push_str("[\"r\",");
+                    push_path(&mut json, path);
+                    json.push(',');
+                    push_string(&mut json, html);
+                }
+                Patch::SetAttribute { path, name, value } => {
+                    json.push_str("[\"s\",");
+                    push_path(&mut json, path);
+                    json.push(',');
+                    push_string(&mut json, name);
+                    json.push(',');
+                    push_string(&mut json, value);
+                }
+                Patch::RemoveAttribute { path, name } => {
+                    json.push_str("[\"u\",");
+                    push_path(&mut json, path);
+                    json.push(',');
+                    push_string(&mut json, name);
+                }
+                Patch::InsertChild { path, index, html } => {
+                    json.push_str("[\"i\",");
+                    push_path(&mut json, path);
+                    let _ = write!(json, ",{index},");
+                    push_string(&mut json, html);
+                }
+                Patch::RemoveChild { path, index } => {
+                    json.push_str("[\"d\",");
+                    push_path(&mut json, path);
+                    let _ = write!(json, ",{index}");
+                }
+            }
+            json.push(']');
+        }
+        json.push(']');
+        json
+    }
+}
+
+fn push_path(json: &mut String, path: &[usize]) {
+    json.push('[');
+    for (i, index) in path.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        let _ = write!(json, "{index}");
+    }
+    json.push(']');
+}
+
+fn push_string(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+fn patch_children(
+    patches: &mut Vec<Patch>,
+    path: &mut Vec<usize>,
+    old: &[Node<'_>],
+    new: &[Node<'_>],
+) {
+    let steps = align(old, new, match_weight);
+    let mut index = 0;
+    let mut steps = steps.into_iter().peekable();
+    while let Some(step) = steps.next() {
+        match step {
+            Step::Match(i, j) => {
+                path.push(index);
+                patch_node(patches, path, &old[i], &new[j]);
+                path.pop();
+                index += 1;
+            }
+            Step::Remove(_) => {
+                if let Some(Step::Insert(j)) = steps.peek() {
+                    let mut node_path = path.clone();
+                    node_path.push(index);
+                    patches.push(Patch::Replace {
+                        path: node_path,
+                        html: new[*j].to_string(),
+                    });
+                    steps.next();
+                    index += 1;
+                } else {
+                    patches.push(Patch::RemoveChild {
+                        path: path.clone(),
+                        index,
+                    });
+                }
+            }
+            Step::Insert(j) => {
+                patches.push(Patch::InsertChild {
+                    path: path.clone(),
+                    index,
+                    html: new[j].to_string(),
+                });
+                index += 1;
+            }
+        }
+    }
+}
+
+fn patch_node(patches: &mut Vec<Patch>, path: &[usize], old: &Node<'_>, new: &Node<'_>) {
+    match (old, new) {
+        (
+            Node::Parent {
+                attributes: old_attributes,
+                children: old_children,
+                ..
+            },
+            Node::Parent {
+                attributes: new_attributes,
+                children: new_children,
+                ..
+            },
+        ) => {
+            patch_attributes(patches, path, old_attributes, new_attributes);
+            patch_children(patches, &mut path.to_vec(), old_children, new_children);
+        }
+        (
+            Node::Void {
+                attributes: old_attributes,
+                ..
+            },
+            Node::Void {
+                attributes: new_attributes,
+                ..
+            },
+        ) => patch_attributes(patches, path, old_attributes, new_attributes),
+        (old, new) if old != new => patches.push(Patch::Replace {
+            path: path.to_vec(),
+            html: new.to_string(),
+        }),
+        _ => (),
+    }
+}
+
+/// Browsers only keep the first occurrence of an attribute, so the following ones are ignored
+fn patch_attributes(patches: &mut Vec<Patch>, path: &[usize], old: &[Attr<'_>], new: &[Attr<'_>]) {
+    for (i, (name, value)) in new.iter().enumerate() {
+        if new[..i].iter().any(|(n, _)| n == name) {
+            continue;
+        }
+        let old_value = old.iter().find(|(n, _)| n == name).map(|(_, v)| v);
+        if old_value.map(value_of) != Some(value_of(value)) {
+            patches.push(Patch::SetAttribute {
+                path: path.to_vec(),
+                name: (*name).into(),
+                value: value_of(value).into(),
+            });
+        }
+    }
+    for (i, (name, _)) in old.iter().enumerate() {
+        if !old[..i].iter().any(|(n, _)| n == name) && !new.iter().any(|(n, _)| n == name) {
+            patches.push(Patch::RemoveAttribute {
+                path: path.to_vec(),
+                name: (*name).into(),
+            });
+        }
+    }
+}
+
+fn value_of<'a>(value: &'a Option<Cow<'_, str>>) -> &'a str {
+    value.as_deref().unwrap_or_default()
+}
+
+/// Keyed elements only match elements with the same key,
+/// and identical nodes are preferred over merely similar ones
+fn match_weight(old: &Node<'_>, new: &Node<'_>) -> Option<usize> {
+    if !same_kind(old, new) || key(old) != key(new) {
+        None
+    } else if old == new {
+        Some(2)
+    } else {
+        Some(1)
+    }
+}
+
+fn key<'a>(node: &'a Node<'_>) -> Option<&'a str> {
+    node.attribute("data-key").or_else(|| node.attribute("id"))
+}
@@ -0,0 +1,329 @@
+//! Validation of the HTML content models
+//!
+//! Browsers silently restructure some invalid trees, for example when a `div` is put inside a `p`,
+//! or when a `form` is nested in another `form`.
+//! [`Element::validate`] and [`Document::validate`] check the tree against the content models
+//! defined by the [HTML specification](https://html.spec.whatwg.org/multipage/dom.html#content-models).
+//!
+//! Unknown elements (including custom elements) and raw HTML are not checked.
+//!
+//! [`AttributeChecker`] (also available via [`Element::check_attributes`] and [`Document::check_attributes`])
+//! checks that attributes are valid for the elements they are set on.
+//!
+//! ## Example
+//!
+//! ```
+//! # use fun_html::{elt, validate::{Violation, ViolationKind}};
+//! let element = elt::ul([], [elt::li([], []), elt::div([], [])]);
+//!
+//! assert_eq!(
+//!     element.validate(),
+//!     [Violation {
+//!         path: "ul > div".into(),
+//!         kind: ViolationKind::InvalidChild { parent: "ul", child: "div" },
+//!     }],
+//! );
+//! ```
+
+mod attributes;
+
+use alloc::{fmt::Display, string::String, vec::Vec};
+
+pub use attributes::{AttributeChecker, AttributeWarning, AttributeWarningKind};
+
+use crate::{
+    normalize::{nodes, paths, Node},
+    Document, Element,
+};
+
+impl Document {
+    /// Check the document against the HTML content models
+    ///
+    /// See the [`validate`](crate::validate) module
+    pub fn validate(&self) -> Vec<Violation> {
+        self.0.validate()
+    }
+}
+
+impl Element {
+    /// Check the element tree against the HTML content models
+    ///
+    /// See the [`validate`](crate::validate) module
+    pub fn validate(&self) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        validate_nodes(&mut violations, "", &mut Vec::new(), &nodes(self));
+        violations
+    }
+}
+
+/// A violation of the HTML content models
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Path of the offending node (e.g. `html > body > ul > div`)
+    pub path: String,
+    /// What is wrong
+    pub kind: ViolationKind,
+}
+
+/// Kind of [`Violation`]
+///
+/// Text nodes are designated by `#text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// The node is not allowed as a child of its parent
+    InvalidChild {
+        /// Tag of the parent
+        parent: &'static str,
+        /// Tag of the child
+        child: &'static str,
+    },
+    /// The element is not allowed inside one of its ancestors
+    InvalidDescendant {
+        /// Tag of the ancestor
+        ancestor: &'static str,
+        /// Tag of the descendant
+        descendant: &'static str,
+    },
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.kind {
+            ViolationKind::InvalidChild { parent, child } => {
+                write!(f, "{}: `{child}` is not allowed in `{parent}`", self.path)
+            }
+            ViolationKind::InvalidDescendant {
+                ancestor,
+                descendant,
+            } => write!(
+                f,
+                "{}: `{descendant}` is not allowed inside `{ancestor}`",
+                self.path
+            ),
+        }
+    }
+}
+
+fn validate_nodes<'a>(
+    violations: &mut Vec<Violation>,
+    parent_path: &str,
+    ancestors: &mut Vec<&'a Node<'a>>,
+    nodes: &'a [Node<'a>],
+) {
+    for (path, node) in paths(parent_path, nodes).iter().zip(nodes) {
+        let child = match node {
+            Node::Parent { tag, .. } | Node::Void { tag, .. } => *tag,
+            Node::Text(text) if text.trim().is_empty() => continue,
+            Node::Text(_) => "#text",
+            Node::Script(_) | Node::Raw(_) => continue,
+        };
+        let categories = categories(node);
+        if let Some(parent) = ancestors.last().and_then(|parent| parent.tag()) {
+            if !is_allowed(ancestors, child, categories) {
+                violations.push(Violation {
+                    path: path.clone(),
+                    kind: ViolationKind::InvalidChild { parent, child },
+                });
+            }
+        }
+        let forbidding_ancestor = ancestors.iter().rev().find_map(|ancestor| {
+            let tag = ancestor.tag()?;
+            let (forbidden_categories, forbidden_tags) = forbidden_descendants(tag);
+            if categories & forbidden_categories != 0 || forbidden_tags.contains(&child) {
+                Some(tag)
+            } else {
+                None
+            }
+        });
+        if let Some(ancestor) = forbidding_ancestor {
+            violations.push(Violation {
+                path: path.clone(),
+                kind: ViolationKind::InvalidDescendant {
+                    ancestor,
+                    descendant: child,
+                },
+            });
+        }
+        if let Node::Parent { children, .. } = node {
+            ancestors.push(node);
+            validate_nodes(violations, path, ancestors, children);
+            ancestors.pop();
+        }
+    }
+}
+
+/// Check the child against the content model of its parent,
+/// or of the nearest non-transparent ancestor
+fn is_allowed(ancestors: &[&Node<'_>], child: &str, categories: u16) -> bool {
+    for ancestor in ancestors.iter().rev() {
+        let model = match ancestor.tag() {
+            Some(tag) => model(tag),
+            None => return true,
+        };
+        if categories & model.categories != 0 || model.tags.contains(&child) {
+            return true;
+        }
+        if !model.transparent {
+            return false;
+        }
+    }
+    true
+}
+
+const METADATA: u16 = 1 << 0;
+const FLOW: u16 = 1 << 1;
+const PHRASING: u16 = 1 << 2;
+const HEADING: u16 = 1 << 3;
+const SECTIONING: u16 = 1 << 4;
+const INTERACTIVE: u16 = 1 << 5;
+const SCRIPT_SUPPORTING: u16 = 1 << 6;
+/// Text, which is part of the phrasing content, but also allowed in text-only elements
+const TEXT: u16 = 1 << 7;
+const ANY: u16 = u16::MAX;
+
+/// Content categories of a node
+fn categories(node: &Node<'_>) -> u16 {
+    let tag = match node {
+        Node::Parent { tag, .. } | Node::Void { tag, .. } => *tag,
+        _ => return FLOW | PHRASING | TEXT,
+    };
+    match tag {
+        "base" | "style" | "title" => METADATA,
+        "link" if is_body_ok_link(node) => METADATA | FLOW | PHRASING,
+        "link" => METADATA,
+        "meta" if node.attribute("itemprop").is_some() => METADATA | FLOW | PHRASING,
+        "meta" => METADATA,
+        "script" | "template" => METADATA | FLOW | PHRASING | SCRIPT_SUPPORTING,
+        "noscript" => METADATA | FLOW | PHRASING,
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "hgroup" => FLOW | HEADING,
+        "article" | "aside" | "nav" | "section" => FLOW | SECTIONING,
+        "details" => FLOW | INTERACTIVE,
+        "address" | "blockquote" | "dialog" | "div" | "dl" | "fieldset" | "figure" | "footer"
+        | "form" | "header" | "hr" | "main" | "menu" | "ol" | "p" | "pre" | "search" | "table"
+        | "ul" => FLOW,
+        "a" if node.attribute("href").is_some() => FLOW | PHRASING | INTERACTIVE,
+        "audio" | "video" if node.attribute("controls").is_some() => FLOW | PHRASING | INTERACTIVE,
+        "img" if node.attribute("usemap").is_some() => FLOW | PHRASING | INTERACTIVE,
+        "input" if node.attribute("type") == Some("hidden") => FLOW | PHRASING,
+        "button" | "embed" | "iframe" | "input" | "label" | "select" | "textarea" => {
+            FLOW | PHRASING | INTERACTIVE
+        }
+        "a" | "abbr" | "area" | "audio" | "b" | "bdi" | "bdo" | "br" | "canvas" | "cite"
+        | "code" | "data" | "datalist" | "del" | "dfn" | "em" | "i" | "img" | "ins" | "kbd"
+        | "map" | "mark" | "math" | "meter" | "object" | "output" | "picture" | "progress"
+        | "q" | "ruby" | "s" | "samp" | "slot" | "small" | "span" | "strong" | "sub" | "sup"
+        | "svg" | "time" | "u" | "var" | "video" | "wbr" => FLOW | PHRASING,
+        "body" | "caption" | "col" | "colgroup" | "dd" | "dt" | "figcaption" | "head" | "html"
+        | "legend" | "li" | "optgroup" | "option" | "rp" | "rt" | "source" | "summary"
+        | "tbody" | "td" | "tfoot" | "th" | "thead" | "tr" | "track" => 0,
+        // Unknown and custom elements
+        _ => FLOW | PHRASING,
+    }
+}
+
+fn is_body_ok_link(node: &Node<'_>) -> bool {
+    node.attribute("rel").map_or(false, |rel| {
+        rel.split_whitespace().all(|rel| {
+            [
+                "dns-prefetch",
+                "modulepreload",
+                "pingback",
+                "preconnect",
+                "prefetch",
+                "preload",
+                "stylesheet",
+            ]
+            .iter()
+            .any(|ok| rel.eq_ignore_ascii_case(ok))
+        })
+    })
+}
+
+/// Content model of an element
+struct Model {
+    /// Categories of the allowed children
+    categories: u16,
+    /// Tags of the allowed children (in addition to `categories`)
+    tags: &'static [&'static str],
+    /// If `true`, children allowed by the parent's content model are allowed too
+    transparent: bool,
+}
+
+const fn only(categories: u16, tags: &'static [&'static str]) -> Model {
+    Model {
+        categories,
+        tags,
+        transparent: false,
+    }
+}
+
+const fn transparent(tags: &'static [&'static str]) -> Model {
+    Model {
+        categories: 0,
+        tags,
+        transparent: true,
+    }
+}
+
+fn model(tag: &str) -> Model {
+    match tag {
+        "html" => only(0, &["head", "body"]),
+        "head" => only(METADATA, &[]),
+        "title" | "textarea" | "option" | "rp" => only(TEXT, &[]),
+        "script" | "style" => only(ANY, &[]),
+        "body" | "div" | "li" | "dd" | "td" | "main" | "section" | "article" | "aside" | "nav"
+        | "header" | "footer" | "blockquote" | "dialog" | "figcaption" | "form" | "address"
+        | "search" | "caption" | "th" | "dt" => only(FLOW, &[]),
+        "figure" => only(FLOW, &["figcaption"]),
+        "details" => only(FLOW, &["summary"]),
+        "fieldset" => only(FLOW, &["legend"]),
+        "legend" | "summary" => only(PHRASING | HEADING, &[]),
+        "p" | "pre" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "small" | "span" | "em"
+        | "strong" | "b" | "i" | "u" | "s" | "code" | "kbd" | "samp" | "var" | "mark" | "abbr"
+        | "cite" | "q" | "dfn" | "sub" | "sup" | "bdi" | "bdo" | "data" | "time" | "label"
+        | "button" | "output" | "progress" | "meter" | "rt" => only(PHRASING, &[]),
+        "hgroup" => only(
+            SCRIPT_SUPPORTING,
+            &["p", "h1", "h2", "h3", "h4", "h5", "h6"],
+        ),
+        "ul" | "ol" | "menu" => only(SCRIPT_SUPPORTING, &["li"]),
+        "dl" => only(SCRIPT_SUPPORTING, &["dt", "dd", "div"]),
+        "table" => only(
+            SCRIPT_SUPPORTING,
+            &["caption", "colgroup", "thead", "tbody", "tfoot", "tr"],
+        ),
+        "thead" | "tbody" | "tfoot" => only(SCRIPT_SUPPORTING, &["tr"]),
+        "tr" => only(SCRIPT_SUPPORTING, &["td", "th"]),
+        "colgroup" => only(0, &["col", "template"]),
+        "select" => only(SCRIPT_SUPPORTING, &["option", "optgroup", "hr"]),
+        "optgroup" => only(SCRIPT_SUPPORTING, &["option"]),
+        "datalist" => only(PHRASING, &["option"]),
+        "picture" => only(SCRIPT_SUPPORTING, &["source", "img"]),
+        "ruby" => only(PHRASING, &["rt", "rp"]),
+        "a" | "ins" | "del" | "noscript" | "object" | "canvas" | "map" | "slot" => transparent(&[]),
+        "audio" | "video" => transparent(&["source", "track"]),
+        "area" | "base" | "br" | "col" | "embed" | "hr" | "iframe" | "img" | "input" | "link"
+        | "meta" | "source" | "track" | "wbr" => only(0, &[]),
+        // Unknown and custom elements
+        _ => only(ANY, &[]),
+    }
+}
+
+/// Categories and tags of the elements not allowed as descendants
+fn forbidden_descendants(tag: &str) -> (u16, &'static [&'static str]) {
+    match tag {
+        "a" => (INTERACTIVE, &["a"]),
+        "button" => (INTERACTIVE, &[]),
+        "form" => (0, &["form"]),
+        "label" => (0, &["label"]),
+        "header" | "footer" => (0, &["header", "footer"]),
+        "address" => (HEADING | SECTIONING, &["header", "footer", "address"]),
+        "th" | "dt" => (HEADING | SECTIONING, &["header", "footer"]),
+        "caption" => (0, &["table"]),
+        "progress" => (0, &["progress"]),
+        "meter" => (0, &["meter"]),
+        "dfn" => (0, &["dfn"]),
+        "audio" | "video" => (0, &["audio", "video"]),
+        _ => (0, &[]),
+    }
+}
@@ -0,0 +1,556 @@
+use alloc::{borrow::Cow, fmt::Display, string::String, vec, vec::Vec};
+
+use crate::{
+    normalize::{nodes, visit, Node},
+    Document, Element,
+};
+
+impl Document {
+    /// Check that the attributes are valid for the elements they are set on
+    ///
+    /// See [`AttributeChecker`]
+    pub fn check_attributes(&self) -> Vec<AttributeWarning> {
+        AttributeChecker::default().check_document(self)
+    }
+}
+
+impl Element {
+    /// Check that the attributes are valid for the elements they are set on
+    ///
+    /// See [`AttributeChecker`]
+    pub fn check_attributes(&self) -> Vec<AttributeWarning> {
+        AttributeChecker::default().check(self)
+    }
+}
+
+/// Checks that attributes are valid for the elements they are set on
+///
+/// It knows the global attributes, and the attributes specific to each element of the HTML specification.
+/// It also checks the values of enumerated attributes (like `type`, `method` or `dir`),
+/// and the limits of table spans (`colspan`, `rowspan` and `span`).
+///
+/// Attributes starting with `data-`, `aria-` or `on` (event handlers) are always allowed,
+/// as well as the attributes of unknown and custom elements.
+/// By default, the prefixes used by common frameworks are allowed too: `hx-`, `x-`, `@` and `:`.
+///
+/// ## Example
+///
+/// ```
+/// # use fun_html::{attr, elt, validate::{AttributeChecker, AttributeWarning, AttributeWarningKind}};
+/// let element = elt::div([attr::href("/"), ("v-if", "visible").into()], []);
+///
+/// assert_eq!(
+///     AttributeChecker::default().allow_prefix("v-").check(&element),
+///     [AttributeWarning {
+///         path: "div".into(),
+///         kind: AttributeWarningKind::UnknownAttribute { tag: "div", attribute: "href".into() },
+///     }],
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct AttributeChecker {
+    allowed_prefixes: Vec<Cow<'static, str>>,
+}
+
+impl Default for AttributeChecker {
+    fn default() -> Self {
+        Self {
+            allowed_prefixes: vec!["hx-".into(), "x-".into(), "@".into(), ":".into()],
+        }
+    }
+}
+
+impl AttributeChecker {
+    /// Create a checker that only allows the prefixes `data-`, `aria-` and `on`
+    pub fn strict() -> Self {
+        Self {
+            allowed_prefixes: Vec::new(),
+        }
+    }
+
+    /// Allow any attribute starting with `prefix`
+    pub fn allow_prefix(mut self, prefix: impl Into<Cow<'static, str>>) -> Self {
+        self.allowed_prefixes.push(prefix.into());
+        self
+    }
+
+    /// Check the attributes of the document
+    pub fn check_document(&self, document: &Document) -> Vec<AttributeWarning> {
+        self.check(&document.0)
+    }
+
+    /// Check the attributes of the element and its descendants
+    pub fn check(&self, element: &Element) -> Vec<AttributeWarning> {
+        let nodes = nodes(element);
+        let mut warnings = Vec::new();
+        visit(&nodes, "", &mut |path, node| {
+            let (tag, attributes) = match node {
+                Node::Parent {
+                    tag, attributes, ..
+                }
+                | Node::Void { tag, attributes } => (*tag, attributes),
+                _ => return,
+            };
+            let specific = match specific_attributes(tag) {
+                Some(specific) => specific,
+                None => return,
+            };
+            for (name, value) in attributes {
+                let known = GLOBAL_ATTRIBUTES.contains(name)
+                    || specific.contains(name)
+                    || ["data-", "aria-", "on"]
+                        .iter()
+                        .any(|prefix| name.starts_with(prefix))
+                    || self
+                        .allowed_prefixes
+                        .iter()
+                        .any(|prefix| name.starts_with(&**prefix));
+                if !known {
+                    warnings.push(AttributeWarning {
+                        path: path.into(),
+                        kind: AttributeWarningKind::UnknownAttribute {
+                            tag,
+                            attribute: (*name).into(),
+                        },
+                    });
+                    continue;
+                }
+                let value = value.as_deref().unwrap_or_default();
+                if !is_valid_value(tag, name, value) {
+                    warnings.push(AttributeWarning {
+                        path: path.into(),
+                        kind: AttributeWarningKind::InvalidValue {
+                            attribute: (*name).into(),
+                            value: value.into(),
+                        },
+                    });
+                }
+            }
+        });
+        warnings
+    }
+}
+
+/// A warning emitted by [`AttributeChecker`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributeWarning {
+    /// Path of the element (e.g. `html > body > div`)
+    pub path: String,
+    /// What is wrong
+    pub kind: AttributeWarningKind,
+}
+
+/// Kind of [`AttributeWarning`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttributeWarningKind {
+    /// The attribute is not allowed on this element
+    UnknownAttribute {
+        /// Tag of the element
+        tag: &'static str,
+        /// Name of the attribute
+        attribute: String,
+    },
+    /// The value is not one of the allowed values of this attribute
+    InvalidValue {
+        /// Name of the attribute
+        attribute: String,
+        /// Value of the attribute (empty for flags)
+        value: String,
+    },
+}
+
+impl Display for AttributeWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.kind {
+            AttributeWarningKind::UnknownAttribute { tag, attribute } => write!(
+                f,
+                "{}: attribute `{attribute}` is not allowed on `{tag}`",
+                self.path
+            ),
+            AttributeWarningKind::InvalidValue { attribute, value } => write!(
+                f,
+                "{}: invalid value {value:?} for attribute `{attribute}`",
+                self.path
+            ),
+        }
+    }
+}
+
+const GLOBAL_ATTRIBUTES: &[&str] = &[
+    "accesskey",
+    "autocapitalize",
+    "autocorrect",
+    "autofocus",
+    "class",
+    "contenteditable",
+    "dir",
+    "draggable",
+    "enterkeyhint",
+    "hidden",
+    "id",
+    "inert",
+    "inputmode",
+    "is",
+    "itemid",
+    "itemprop",
+    "itemref",
+    "itemscope",
+    "itemtype",
+    "lang",
+    "nonce",
+    "popover",
+    "role",
+    "slot",
+    "spellcheck",
+    "style",
+    "tabindex",
+    "title",
+    "translate",
+    "writingsuggestions",
+];
+
+/// Attributes specific to an element, or `None` for unknown elements
+fn specific_attributes(tag: &str) -> Option<&'static [&'static str]> {
+    Some(match tag {
+        "a" => &[
+            "href",
+            "target",
+            "download",
+            "ping",
+            "rel",
+            "hreflang",
+            "type",
+            "referrerpolicy",
+        ],
+        "area" => &[
+            "alt",
+            "coords",
+            "shape",
+            "href",
+            "target",
+            "download",
+            "ping",
+            "rel",
+            "referrerpolicy",
+        ],
+        "audio" => &[
+            "src",
+            "crossorigin",
+            "preload",
+            "autoplay",
+            "loop",
+            "muted",
+            "controls",
+        ],
+        "video" => &[
+            "src",
+            "crossorigin",
+            "poster",
+            "preload",
+            "autoplay",
+            "playsinline",
+            "loop",
+            "muted",
+            "controls",
+            "width",
+            "height",
+        ],
+        "base" => &["href", "target"],
+        "blockquote" | "q" => &["cite"],
+        "del" | "ins" => &["cite", "datetime"],
+        "button" => &[
+            "command",
+            "commandfor",
+            "disabled",
+            "form",
+            "formaction",
+            "formenctype",
+            "formmethod",
+            "formnovalidate",
+            "formtarget",
+            "name",
+            "popovertarget",
+            "popovertargetaction",
+            "type",
+            "value",
+        ],
+        "canvas" => &["width", "height"],
+        "col" | "colgroup" => &["span"],
+        "data" => &["value"],
+        "details" => &["open", "name"],
+        "dialog" => &["open"],
+        "embed" => &["src", "type", "width", "height"],
+        "fieldset" => &["disabled", "form", "name"],
+        "form" => &[
+            "accept-charset",
+            "action",
+            "autocomplete",
+            "enctype",
+            "method",
+            "name",
+            "novalidate",
+            "target",
+            "rel",
+        ],
+        "html" => &["xmlns"],
+        "iframe" => &[
+            "src",
+            "srcdoc",
+            "name",
+            "sandbox",
+            "allow",
+            "allowfullscreen",
+            "width",
+            "height",
+            "referrerpolicy",
+            "loading",
+        ],
+        "img" => &[
+            "alt",
+            "src",
+            "srcset",
+            "sizes",
+            "crossorigin",
+            "usemap",
+            "ismap",
+            "width",
+            "height",
+            "referrerpolicy",
+            "decoding",
+            "loading",
+            "fetchpriority",
+        ],
+        "input" => &[
+            "accept",
+            "alt",
+            "autocomplete",
+            "capture",
+            "checked",
+            "dirname",
+            "disabled",
+            "form",
+            "formaction",
+            "formenctype",
+            "formmethod",
+            "formnovalidate",
+            "formtarget",
+            "height",
+            "list",
+            "max",
+            "maxlength",
+            "min",
+            "minlength",
+            "multiple",
+            "name",
+            "pattern",
+            "placeholder",
+            "popovertarget",
+            "popovertargetaction",
+            "readonly",
+            "required",
+            "size",
+            "src",
+            "step",
+            "type",
+            "value",
+            "width",
+        ],
+        "label" => &["for"],
+        "li" => &["value"],
+        "link" => &[
+            "href",
+            "crossorigin",
+            "rel",
+            "as",
+            "media",
+            "hreflang",
+            "type",
+            "sizes",
+            "imagesrcset",
+            "imagesizes",
+            "referrerpolicy",
+            "integrity",
+            "blocking",
+            "color",
+            "disabled",
+            "fetchpriority",
+        ],
+        "map" => &["name"],
+        "meta" => &[
+            "name",
+            "http-equiv",
+            "content",
+            "charset",
+            "media",
+            "property",
+        ],
+        "meter" => &["value", "min", "max", "low", "high", "optimum"],
+        "object" => &["data", "type", "name", "form", "width", "height"],
+        "ol" => &["reversed", "start", "type"],
+        "optgroup" => &["disabled", "label"],
+        "option" => &["disabled", "label", "selected", "value"],
+        "output" => &["for", "form", "name"],
+        "progress" => &["value", "max"],
+        "script" => &[
+            "src",
+            "type",
+            "nomodule",
+            "async",
+            "defer",
+            "crossorigin",
+            "integrity",
+            "referrerpolicy",
+            "blocking",
+            "fetchpriority",
+        ],
+        "select" => &[
+            "autocomplete",
+            "disabled",
+            "form",
+            "multiple",
+            "name",
+            "required",
+            "size",
+        ],
+        "slot" => &["name"],
+        "source" => &["type", "media", "src", "srcset", "sizes", "width", "height"],
+        "style" => &["media", "blocking"],
+        "td" => &["colspan", "rowspan", "headers"],
+        "th" => &["colspan", "rowspan", "headers", "scope", "abbr"],
+        "template" => &[
+            "shadowrootmode",
+            "shadowrootdelegatesfocus",
+            "shadowrootclonable",
+            "shadowrootserializable",
+        ],
+        "textarea" => &[
+            "autocomplete",
+            "cols",
+            "dirname",
+            "disabled",
+            "form",
+            "maxlength",
+            "minlength",
+            "name",
+            "placeholder",
+            "readonly",
+            "required",
+            "rows",
+            "wrap",
+        ],
+        "time" => &["datetime"],
+        "track" => &["default", "kind", "label", "src", "srclang"],
+        "abbr" | "address" | "article" | "aside" | "b" | "bdi" | "bdo" | "body" | "br"
+        | "caption" | "cite" | "code" | "datalist" | "dd" | "dfn" | "div" | "dl" | "dt" | "em"
+        | "figcaption" | "figure" | "footer" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "head"
+        | "header" | "hgroup" | "hr" | "i" | "kbd" | "legend" | "main" | "mark" | "menu"
+        | "nav" | "noscript" | "p" | "picture" | "pre" | "rp" | "rt" | "ruby" | "s" | "samp"
+        | "search" | "section" | "small" | "span" | "strong" | "sub" | "summary" | "sup"
+        | "table" | "tbody" | "tfoot" | "thead" | "title" | "tr" | "u" | "ul" | "var" | "wbr" => {
+            &[]
+        }
+        _ => return None,
+    })
+}
+
+fn is_valid_value(tag: &str, attribute: &str, value: &str) -> bool {
+    let allowed: &[&str] = match (tag, attribute) {
+        ("input", "type") => &[
+            "hidden",
+            "text",
+            "search",
+            "tel",
+            "url",
+            "email",
+            "password",
+            "date",
+            "month",
+            "week",
+            "time",
+            "datetime-local",
+            "number",
+            "range",
+            "color",
+            "checkbox",
+            "radio",
+            "file",
+            "submit",
+            "image",
+            "reset",
+            "button",
+        ],
+        ("button", "type") => &["submit", "reset", "button"],
+        ("ol", "type") => return ["1", "a", "A", "i", "I"].contains(&value),
+        (_, "method" | "formmethod") => &["get", "post", "dialog"],
+        (_, "enctype" | "formenctype") => &[
+            "application/x-www-form-urlencoded",
+            "multipart/form-data",
+            "text/plain",
+        ],
+        ("form", "autocomplete") => &["on", "off"],
+        (_, "crossorigin") => &["", "anonymous", "use-credentials"],
+        (_, "referrerpolicy") => &[
+            "",
+            "no-referrer",
+            "no-referrer-when-downgrade",
+            "same-origin",
+            "origin",
+            "strict-origin",
+            "origin-when-cross-origin",
+            "strict-origin-when-cross-origin",
+            "unsafe-url",
+        ],
+        (_, "loading") => &["lazy", "eager"],
+        (_, "decoding") => &["sync", "async", "auto"],
+        (_, "fetchpriority") => &["high", "low", "auto"],
+        (_, "preload") => &["", "none", "metadata", "auto"],
+        (_, "dir") => &["ltr", "rtl", "auto"],
+        (_, "draggable") => &["true", "false"],
+        (_, "spellcheck") => &["", "true", "false"],
+        (_, "contenteditable") => &["", "true", "false", "plaintext-only"],
+        (_, "translate") => &["", "yes", "no"],
+        (_, "hidden") => &["", "hidden", "until-found"],
+        (_, "popover") => &["", "auto", "manual", "hint"],
+        (_, "popovertargetaction") => &["toggle", "show", "hide"],
+        ("button", "command") if value.starts_with("--") => return true,
+        ("button", "command") => &[
+            "show-modal",
+            "close",
+            "request-close",
+            "show-popover",
+            "hide-popover",
+            "toggle-popover",
+        ],
+        (_, "enterkeyhint") => &["enter", "done", "go", "next", "previous", "search", "send"],
+        (_, "inputmode") => &[
+            "none", "text", "decimal", "numeric", "tel", "search", "email", "url",
+        ],
+        (_, "autocapitalize") => &["off", "none", "on", "sentences", "words", "characters"],
+        ("textarea", "wrap") => &["soft", "hard"],
+        ("th", "scope") => &["row", "col", "rowgroup", "colgroup"],
+        ("td" | "th", "colspan") | ("col" | "colgroup", "span") => {
+            return is_integer_in(value, 1, 1000)
+        }
+        ("td" | "th", "rowspan") => return is_integer_in(value, 0, 65534),
+        ("track", "kind") => &[
+            "subtitles",
+            "captions",
+            "descriptions",
+            "chapters",
+            "metadata",
+        ],
+        ("area", "shape") => &["rect", "circle", "poly", "default"],
+        _ => return true,
+    };
+    allowed
+        .iter()
+        .any(|allowed| allowed.eq_ignore_ascii_case(value))
+}
+
+fn is_integer_in(value: &str, min: u32, max: u32) -> bool {
+    !value.is_empty()
+        && value.bytes().all(|b| b.is_ascii_digit())
+        && value
+            .parse::<u32>()
+            .map_or(false, |value| (min..=max).contains(&value))
+}
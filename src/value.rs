@@ -0,0 +1,179 @@
+use alloc::{
+    fmt::{Debug, Display, Write},
+    string::ToString,
+    sync::Arc,
+};
+use core::hash::{Hash, Hasher};
+
+/// Numeric attribute value
+///
+/// It can be created from any integer type, and from `f32` and `f64`.
+///
+/// Floats are rendered as valid HTML floating-point numbers,
+/// with `.` as decimal separator regardless of the locale, and without exponent.
+///
+/// See [`Attribute::new_number`](crate::Attribute::new_number)
+#[derive(Debug, Clone, Copy)]
+pub struct Number(Repr);
+
+#[derive(Debug, Clone, Copy)]
+enum Repr {
+    Signed(i128),
+    Unsigned(u128),
+    F32(f32),
+    F64(f64),
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Number {
+            fn from(value: $t) -> Self {
+                let value = i128::from(value);
+                if value < 0 {
+                    Self(Repr::Signed(value))
+                } else {
+                    Self(Repr::Unsigned(value as u128))
+                }
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Number {
+            fn from(value: $t) -> Self {
+                Self(Repr::Unsigned(value.into()))
+            }
+        })*
+    };
+}
+
+impl_from_signed!(i8, i16, i32, i64, i128);
+impl_from_unsigned!(u8, u16, u32, u64, u128);
+
+impl From<isize> for Number {
+    fn from(value: isize) -> Self {
+        (value as i128).into()
+    }
+}
+
+impl From<usize> for Number {
+    fn from(value: usize) -> Self {
+        (value as u128).into()
+    }
+}
+
+impl From<f32> for Number {
+    fn from(value: f32) -> Self {
+        Self(Repr::F32(value))
+    }
+}
+
+impl From<f64> for Number {
+    fn from(value: f64) -> Self {
+        Self(Repr::F64(value))
+    }
+}
+
+impl Number {
+    pub(crate) fn is_finite(self) -> bool {
+        match self.0 {
+            Repr::Signed(_) | Repr::Unsigned(_) => true,
+            Repr::F32(value) => value.is_finite(),
+            Repr::F64(value) => value.is_finite(),
+        }
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Repr::Signed(value) => write!(f, "{value}"),
+            Repr::Unsigned(value) => write!(f, "{value}"),
+            Repr::F32(value) => write!(f, "{value}"),
+            Repr::F64(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Floats are compared by their bit pattern, so that `Eq` and `Hash` are consistent
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.0, other.0) {
+            (Repr::Signed(a), Repr::Signed(b)) => a == b,
+            (Repr::Unsigned(a), Repr::Unsigned(b)) => a == b,
+            (Repr::F32(a), Repr::F32(b)) => a.to_bits() == b.to_bits(),
+            (Repr::F64(a), Repr::F64(b)) => a.to_bits() == b.to_bits(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Number {}
+
+impl Hash for Number {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(&self.0).hash(state);
+        match self.0 {
+            Repr::Signed(value) => value.hash(state),
+            Repr::Unsigned(value) => value.hash(state),
+            Repr::F32(value) => value.to_bits().hash(state),
+            Repr::F64(value) => value.to_bits().hash(state),
+        }
+    }
+}
+
+/// Attribute value formatted at render time
+///
+/// Values are compared and hashed by their formatted value
+#[derive(Clone)]
+pub(crate) struct LazyValue(Arc<dyn Display + Send + Sync>);
+
+impl LazyValue {
+    pub(crate) fn new(value: impl Display + Send + Sync + 'static) -> Self {
+        Self(Arc::new(value))
+    }
+
+    /// Write the value escaped as a double-quoted attribute value
+    pub(crate) fn write_escaped(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(Escaper(f), "{}", self.0)
+    }
+}
+
+impl Display for LazyValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Debug for LazyValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("LazyValue")
+            .field(&self.0.to_string())
+            .finish()
+    }
+}
+
+impl PartialEq for LazyValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl Eq for LazyValue {}
+
+impl Hash for LazyValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_string().hash(state);
+    }
+}
+
+struct Escaper<'a, 'b>(&'a mut core::fmt::Formatter<'b>);
+
+impl Write for Escaper<'_, '_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0
+            .write_str(&html_escape::encode_double_quoted_attribute(s))
+    }
+}
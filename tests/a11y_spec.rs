@@ -0,0 +1,229 @@
+use rstest::rstest;
+
+use fun_html::{
+    a11y::{AccessibleNode, Finding, Rule},
+    attr, elt, html, Element,
+};
+
+fn finding(rule: Rule, path: &str) -> Finding {
+    Finding {
+        rule,
+        path: path.into(),
+    }
+}
+
+#[rstest]
+#[case(elt::img([attr::src("a.png"), attr::alt("")]))]
+#[case(elt::div([], [elt::label([attr::for_("a")], ["A".into()]), elt::input([attr::id("a")])]))]
+#[case(elt::label([], ["A".into(), elt::select([], [])]))]
+#[case(elt::textarea([("aria-label", "A").into()], ""))]
+#[case(elt::input([attr::type_hidden()]))]
+#[case(elt::input([attr::type_submit()]))]
+#[case(elt::div([], [elt::h1([], []), elt::h2([], []), elt::h3([], []), elt::h2([], []), elt::h1([], [])]))]
+#[case(elt::a([attr::href("/")], [elt::span([], ["home".into()])]))]
+#[case(elt::a([attr::href("/")], [elt::img([attr::src("home.png"), attr::alt("home")])]))]
+#[case(elt::button([("aria-label", "Close").into()], []))]
+#[case(elt::button([("title", "Close").into()], []))]
+#[case(elt::table([], [elt::tr([], [elt::th([], ["A".into()])])]))]
+fn should_not_find_issues(#[case] element: Element) {
+    assert_eq!(element.check_a11y(), []);
+}
+
+#[rstest]
+#[case(elt::img([attr::src("a.png")]), vec![finding(Rule::ImgAlt, "img")])]
+#[case(
+    elt::div([], [elt::label([attr::for_("b")], ["A".into()]), elt::input([attr::id("a")])]),
+    vec![finding(Rule::ControlLabel, "div > input")],
+)]
+#[case(elt::select([], []), vec![finding(Rule::ControlLabel, "select")])]
+#[case(
+    elt::div([], [elt::h1([], []), elt::h3([], [])]),
+    vec![finding(Rule::HeadingOrder, "div > h3")],
+)]
+#[case(elt::a([attr::href("/")], [" ".into()]), vec![finding(Rule::EmptyLink, "a")])]
+#[case(
+    elt::a([attr::href("/")], [elt::img([attr::src("a.png"), attr::alt("")])]),
+    vec![finding(Rule::EmptyLink, "a")],
+)]
+#[case(elt::button([], []), vec![finding(Rule::EmptyButton, "button")])]
+#[case(
+    elt::table([], [elt::tr([], [elt::td([], ["A".into()])])]),
+    vec![finding(Rule::TableHeader, "table")],
+)]
+fn should_find_issues(#[case] element: Element, #[case] expected: Vec<Finding>) {
+    assert_eq!(element.check_a11y(), expected);
+}
+
+#[test]
+fn should_require_lang_on_document() {
+    let doc = html([], [elt::head([], []), elt::body([], [])]);
+    assert_eq!(doc.check_a11y(), [finding(Rule::HtmlLang, "html")]);
+    let doc = html([attr::lang("en")], [elt::head([], []), elt::body([], [])]);
+    assert_eq!(doc.check_a11y(), []);
+}
+
+#[test]
+fn should_display_findings_with_rule_id() {
+    let findings = elt::div([], [elt::button([], [])]).check_a11y();
+    assert_eq!(findings[0].rule.id(), "empty-button");
+    assert_eq!(
+        findings[0].to_string(),
+        "div > button: button without text (empty-button)"
+    );
+}
+
+#[rstest]
+#[case(elt::button([], ["Save".into()]), "button", "Save")]
+#[case(elt::a([attr::href("/")], ["Home".into()]), "link", "Home")]
+#[case(elt::h2([], ["Title".into()]), "heading", "Title")]
+#[case(elt::img([attr::src("a.png"), attr::alt("Logo")]), "img", "Logo")]
+#[case(elt::input([attr::type_checkbox(), ("aria-label", "Agree").into()]), "checkbox", "Agree")]
+#[case(elt::input([attr::type_submit()]), "button", "Submit")]
+#[case(elt::input([attr::type_reset(), attr::value("Clear")]), "button", "Clear")]
+#[case(elt::input([attr::type_range(), ("title", "Volume").into()]), "slider", "Volume")]
+#[case(elt::textarea([("aria-label", "Comment").into()], ""), "textbox", "Comment")]
+#[case(elt::select([], []), "combobox", "")]
+#[case(elt::div([attr::role_str("button")], ["Ok".into()]), "button", "Ok")]
+#[case(Element::new("nav", [("aria-label", "Main").into()], []), "navigation", "Main")]
+#[case(elt::ul([], []), "list", "")]
+fn should_compute_role_and_name(#[case] element: Element, #[case] role: &str, #[case] name: &str) {
+    let tree = element.accessibility_tree();
+    assert_eq!(tree.roots().len(), 1, "{tree}");
+    assert_eq!(tree.roots()[0].role, role);
+    assert_eq!(tree.roots()[0].name, name);
+}
+
+#[rstest]
+#[case(elt::div([], [elt::label([attr::for_("a")], ["Email".into()]), elt::input([attr::id("a")])]))]
+#[case(elt::label([], ["Email ".into(), elt::input([])]))]
+#[case(elt::div([], [
+    elt::span([attr::id("x")], ["Email".into()]),
+    elt::input([("aria-labelledby", "x").into(), ("aria-label", "Other").into()]),
+]))]
+#[case(elt::input([("aria-label", "  Email\n").into()]))]
+fn should_compute_name_of_textbox(#[case] element: Element) {
+    assert_eq!(
+        element
+            .accessibility_tree()
+            .get_by_role("textbox", "Email")
+            .role,
+        "textbox"
+    );
+}
+
+#[test]
+fn should_compute_name_from_descendants() {
+    let element = elt::button(
+        [],
+        [
+            "Open ".into(),
+            elt::img([attr::src("a.png"), attr::alt("the menu")]),
+            elt::span([("aria-hidden", "true").into()], ["▼".into()]),
+        ],
+    );
+    assert_eq!(
+        element.accessibility_tree().roots()[0].name,
+        "Open the menu"
+    );
+}
+
+#[test]
+fn should_omit_hidden_and_generic_elements() {
+    let element = elt::div(
+        [],
+        [
+            elt::span([], [elt::button([], ["A".into()])]),
+            elt::button([("hidden", "").into()], ["B".into()]),
+            elt::div(
+                [("aria-hidden", "true").into()],
+                [elt::button([], ["C".into()])],
+            ),
+            elt::img([attr::src("a.png"), attr::alt("")]),
+        ],
+    );
+    assert_eq!(element.accessibility_tree().to_string(), "button \"A\"");
+}
+
+#[test]
+fn should_build_nested_tree() {
+    let element = elt::main(
+        [],
+        [
+            elt::header([], [elt::h1([], ["Title".into()])]),
+            elt::ul([], [elt::li([], ["a".into()]), elt::li([], ["b".into()])]),
+        ],
+    );
+    let tree = element.accessibility_tree();
+    assert_eq!(
+        tree.to_string(),
+        "main\n  heading \"Title\"\n  list\n    listitem\n    listitem"
+    );
+    assert_eq!(tree.query_all_by_role("listitem").len(), 2);
+    assert_eq!(
+        tree.query_all_by_role("listitem")[1].path,
+        "main > ul > li[2]"
+    );
+}
+
+#[test]
+fn should_expose_landmarks_of_document() {
+    let doc = html(
+        [attr::lang("en")],
+        [
+            elt::head([], [elt::title([], "Page")]),
+            elt::body(
+                [],
+                [
+                    elt::header([], []),
+                    elt::section([], []),
+                    elt::section([("aria-label", "News").into()], []),
+                    elt::footer([], []),
+                ],
+            ),
+        ],
+    );
+    assert_eq!(
+        doc.accessibility_tree().to_string(),
+        "document\n  banner\n  region \"News\"\n  contentinfo"
+    );
+}
+
+#[test]
+fn should_query_by_role_and_name() {
+    let element = elt::form(
+        [],
+        [
+            elt::button([], ["Save".into()]),
+            elt::button([], ["Cancel".into()]),
+        ],
+    );
+    let tree = element.accessibility_tree();
+    let expected = AccessibleNode {
+        role: "button".into(),
+        name: "Cancel".into(),
+        path: "form > button[2]".into(),
+        children: Vec::new(),
+    };
+    assert_eq!(tree.get_by_role("button", "Cancel"), &expected);
+    assert_eq!(tree.query_by_role("button", "Delete"), None);
+    assert_eq!(tree.query_by_role("link", "Save"), None);
+}
+
+#[test]
+#[should_panic(expected = "found no node with role \"button\" and name \"Delete\"")]
+fn get_by_role_should_panic_if_not_found() {
+    elt::button([], ["Save".into()])
+        .accessibility_tree()
+        .get_by_role("button", "Delete");
+}
+
+#[test]
+#[should_panic(expected = "found multiple nodes")]
+fn get_by_role_should_panic_if_ambiguous() {
+    elt::div(
+        [],
+        [elt::button([], ["A".into()]), elt::button([], ["A".into()])],
+    )
+    .accessibility_tree()
+    .get_by_role("button", "A");
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "chrono_v04")]
+
+use chrono_v04::{Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use rstest::rstest;
+
+use fun_html::{attr, attr::HtmlDateTime, elt};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn time(hour: u32, minute: u32, second: u32, milli: u32) -> NaiveTime {
+    NaiveTime::from_hms_milli_opt(hour, minute, second, milli).unwrap()
+}
+
+fn date_time(date: NaiveDate, time: NaiveTime) -> NaiveDateTime {
+    date.and_time(time)
+}
+
+#[rstest]
+#[case(date(2024, 3, 1), "2024-03-01")]
+#[case(date(987, 12, 31), "0987-12-31")]
+#[case(date(12345, 1, 1), "12345-01-01")]
+#[case(time(9, 5, 0, 0), "09:05")]
+#[case(time(23, 59, 7, 0), "23:59:07")]
+#[case(time(0, 0, 0, 250), "00:00:00.25")]
+#[case(time(12, 30, 5, 7), "12:30:05.007")]
+#[case(date_time(date(2024, 3, 1), time(14, 30, 0, 0)), "2024-03-01T14:30")]
+#[case(
+    date_time(date(2024, 3, 1), time(14, 30, 1, 500)),
+    "2024-03-01T14:30:01.5"
+)]
+#[case(Utc.with_ymd_and_hms(2024, 3, 1, 14, 30, 0).unwrap(), "2024-03-01T14:30Z")]
+#[case(
+    FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2024, 3, 1, 14, 30, 0).unwrap(),
+    "2024-03-01T14:30+01:00"
+)]
+#[case(
+    FixedOffset::west_opt(9 * 3600 + 30 * 60).unwrap().with_ymd_and_hms(2024, 3, 1, 14, 30, 0).unwrap(),
+    "2024-03-01T14:30-09:30"
+)]
+#[case(Duration::zero(), "PT0S")]
+#[case(Duration::minutes(90), "PT1H30M")]
+#[case(Duration::days(2) + Duration::seconds(5), "P2DT5S")]
+#[case(Duration::days(1), "P1D")]
+#[case(Duration::milliseconds(1500), "PT1.5S")]
+#[case(Duration::seconds(-1), "PT0S")]
+#[case(date(0, 1, 1), "0001-01-01")]
+#[case(date_time(date(-5, 6, 1), time(14, 30, 0, 0)), "0001-01-01T00:00")]
+#[case(Utc.with_ymd_and_hms(0, 12, 31, 23, 0, 0).unwrap(), "0001-01-01T00:00Z")]
+#[case(
+    FixedOffset::east_opt(3600 + 30).unwrap().with_ymd_and_hms(2024, 3, 1, 14, 30, 0).unwrap(),
+    "2024-03-01T13:29:30Z"
+)]
+fn should_convert_to_html_datetime(#[case] value: impl Into<HtmlDateTime>, #[case] expected: &str) {
+    assert_eq!(value.into().to_string(), expected);
+}
+
+#[test]
+fn should_render_date_attributes() {
+    assert_eq!(
+        elt::input([
+            attr::type_date(),
+            attr::min_date(date(2024, 1, 1)),
+            attr::max_date(date(2024, 12, 31)),
+            attr::value_date(date(2024, 3, 1)),
+        ])
+        .to_string(),
+        r#"<input type="date" min="2024-01-01" max="2024-12-31" value="2024-03-01">"#
+    );
+    assert_eq!(
+        elt::time_datetime(date(2024, 3, 1), ["March 1st".into()]).to_string(),
+        r#"<time datetime="2024-03-01">March 1st</time>"#
+    );
+}
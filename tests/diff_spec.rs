@@ -0,0 +1,115 @@
+use rstest::rstest;
+
+use fun_html::{attr, diff, elt, html, Change, ChangeKind, Element};
+
+fn change(path: &str, kind: ChangeKind) -> Change {
+    Change {
+        path: path.into(),
+        kind,
+    }
+}
+
+#[rstest]
+#[case(elt::div([], []), elt::div([], []))]
+#[case(
+    elt::div([attr::id("a"), attr::class(["b"])], ["hello ".into(), "world".into()]),
+    elt::div([attr::class(["b"]), attr::id("a")], ["hello world".into()]),
+)]
+fn should_find_no_difference(#[case] old: Element, #[case] new: Element) {
+    let diff = diff(&old, &new);
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "no difference");
+}
+
+#[rstest]
+#[case(
+    elt::div([], []),
+    elt::span([], []),
+    vec![
+        change("div", ChangeKind::Removed("<div></div>".into())),
+        change("span", ChangeKind::Added("<span></span>".into())),
+    ],
+)]
+#[case(
+    elt::p([], ["a".into()]),
+    elt::p([], ["b".into()]),
+    vec![change("p > #text", ChangeKind::Changed { from: "a".into(), to: "b".into() })],
+)]
+#[case(
+    elt::ul([], [elt::li([], ["a".into()]), elt::li([], ["c".into()])]),
+    elt::ul([], [elt::li([], ["a".into()]), elt::li([], ["b".into()]), elt::li([], ["c".into()])]),
+    vec![change("ul > li[2]", ChangeKind::Added("<li>b</li>".into()))],
+)]
+#[case(
+    elt::ul([], [elt::li([], ["a".into()]), elt::li([], ["b".into()]), elt::li([], ["c".into()])]),
+    elt::ul([], [elt::li([], ["a".into()]), elt::li([], ["c".into()])]),
+    vec![change("ul > li[2]", ChangeKind::Removed("<li>b</li>".into()))],
+)]
+#[case(
+    elt::div([attr::id("a"), attr::disabled()], []),
+    elt::div([attr::id("b"), attr::class(["c"])], []),
+    vec![
+        change("div", ChangeKind::AttributeRemoved { name: "disabled".into(), value: None }),
+        change("div", ChangeKind::AttributeChanged { name: "id".into(), from: Some("a".into()), to: Some("b".into()) }),
+        change("div", ChangeKind::AttributeAdded { name: "class".into(), value: Some("c".into()) }),
+    ],
+)]
+fn should_report_changes(
+    #[case] old: Element,
+    #[case] new: Element,
+    #[case] expected: Vec<Change>,
+) {
+    assert_eq!(diff(&old, &new).changes(), expected);
+}
+
+#[test]
+fn should_display_changes_with_path() {
+    let old = html(
+        [],
+        [elt::body(
+            [],
+            [elt::main(
+                [],
+                [elt::ul(
+                    [],
+                    [elt::li([], ["a".into()]), elt::li([], ["b".into()])],
+                )],
+            )],
+        )],
+    );
+    let new = html(
+        [],
+        [elt::body(
+            [],
+            [elt::main(
+                [],
+                [elt::ul(
+                    [],
+                    [
+                        elt::li([], ["a".into()]),
+                        elt::li([], ["b".into()]),
+                        elt::li([attr::id("c")], ["c".into()]),
+                    ],
+                )],
+            )],
+        )],
+    );
+    assert_eq!(
+        old.diff(&new).to_string(),
+        "html > body > main > ul > li[3]: added <li id=\"c\">c</li>"
+    );
+    assert_eq!(
+        new.diff(&old).to_string(),
+        "html > body > main > ul > li[3]: removed <li id=\"c\">c</li>"
+    );
+}
+
+#[test]
+fn should_display_one_change_per_line() {
+    let old = elt::div([attr::disabled()], ["a".into()]);
+    let new = elt::div([attr::id("x")], ["b".into()]);
+    assert_eq!(
+        diff(&old, &new).to_string(),
+        "div: removed attribute disabled\ndiv: added attribute id=\"x\"\ndiv > #text: changed \"a\" to \"b\""
+    );
+}
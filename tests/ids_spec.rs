@@ -0,0 +1,83 @@
+use rstest::rstest;
+
+use fun_html::{attr, elt, html, Element, IdIssue, IdIssueKind};
+
+fn issue(path: &str, kind: IdIssueKind) -> IdIssue {
+    IdIssue {
+        path: path.into(),
+        kind,
+    }
+}
+
+#[rstest]
+#[case(elt::div([], []))]
+#[case(elt::div([], [elt::p([attr::id("a")], []), elt::p([attr::id("b")], [])]))]
+#[case(elt::div([], [elt::label([attr::for_("a")], []), elt::input([attr::id("a")])]))]
+#[case(elt::div([], [elt::a([attr::href("#a")], []), elt::h2([attr::id("a")], [])]))]
+#[case(elt::a([attr::href("#")], []))]
+#[case(elt::a([attr::href("#top")], []))]
+#[case(elt::a([attr::href("/page#a")], []))]
+#[case(elt::div([], [elt::a([attr::href("#caf%C3%A9")], []), elt::h2([attr::id("café")], [])]))]
+#[case(elt::div([], [elt::a([attr::href("#a%+1")], []), elt::h2([attr::id("a%+1")], [])]))]
+#[case(elt::div([], [elt::a([attr::href("#a%20b")], []), elt::h2([attr::id("a b")], [])]))]
+#[case(elt::div([], [elt::a([attr::href("#100%")], []), elt::h2([attr::id("100%")], [])]))]
+fn should_not_find_issues(#[case] element: Element) {
+    assert_eq!(element.check_ids(), []);
+}
+
+#[rstest]
+#[case(
+    elt::div([], [elt::p([attr::id("a")], []), elt::p([attr::id("a")], [])]),
+    vec![issue("div > p[2]", IdIssueKind::DuplicateId("a".into()))],
+)]
+#[case(
+    elt::div([attr::id("a")], [elt::p([attr::id("a")], [elt::span([attr::id("a")], [])])]),
+    vec![
+        issue("div > p", IdIssueKind::DuplicateId("a".into())),
+        issue("div > p > span", IdIssueKind::DuplicateId("a".into())),
+    ],
+)]
+#[case(
+    elt::div([], [elt::label([attr::for_("b")], []), elt::input([attr::id("a")])]),
+    vec![issue("div > label", IdIssueKind::UnknownLabelTarget("b".into()))],
+)]
+#[case(
+    elt::div([], [elt::a([attr::href("#b")], []), elt::h2([attr::id("a")], [])]),
+    vec![issue("div > a", IdIssueKind::BrokenFragmentLink("b".into()))],
+)]
+#[case(
+    elt::div([], [elt::a([attr::href("#a")], []), elt::h2([attr::id("b"), attr::id("a")], [])]),
+    vec![issue("div > a", IdIssueKind::BrokenFragmentLink("a".into()))],
+)]
+#[case(
+    elt::div([], [elt::a([attr::href("#caf%C3%A9")], []), elt::h2([attr::id("cafe")], [])]),
+    vec![issue("div > a", IdIssueKind::BrokenFragmentLink("caf%C3%A9".into()))],
+)]
+#[case(
+    elt::div([], [elt::a([attr::href("#a%+1")], []), elt::h2([attr::id("a\u{1}")], [])]),
+    vec![issue("div > a", IdIssueKind::BrokenFragmentLink("a%+1".into()))],
+)]
+fn should_find_issues(#[case] element: Element, #[case] expected: Vec<IdIssue>) {
+    assert_eq!(element.check_ids(), expected);
+}
+
+#[test]
+fn should_check_document() {
+    let doc = html(
+        [],
+        [elt::body(
+            [],
+            [
+                elt::main([attr::id("main")], []),
+                elt::a([attr::href("#main")], []),
+                elt::a([attr::href("#section")], []),
+            ],
+        )],
+    );
+    let issues = doc.check_ids();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(
+        issues[0].to_string(),
+        "html > body > a[2]: link to unknown fragment \"section\""
+    );
+}
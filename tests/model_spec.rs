@@ -1,6 +1,17 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
 use rstest::rstest;
 
-use fun_html::{Attribute, Element};
+use fun_html::{
+    attr::{
+        self,
+        css::{Length, Style},
+    },
+    elt, html, Attribute, Document, Element,
+};
 
 #[rstest]
 #[cfg(debug_assertions)]
@@ -37,3 +48,157 @@ fn should_panic_for_invalid_void_element_name(
 ) {
     Element::new_void(name, []);
 }
+
+#[rstest]
+#[case(elt::div([], []), elt::div([], []))]
+#[case(elt::div([attr::id("foo")], ["hello".into()]), elt::div([attr::id("foo")], ["hello".into()]))]
+#[case(elt::none(), Element::default())]
+fn should_be_structurally_equal(#[case] a: Element, #[case] b: Element) {
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+    assert!(a.eq_normalized(&b));
+}
+
+#[rstest]
+#[case(elt::div([], []), elt::span([], []))]
+#[case(elt::div([attr::id("foo")], []), elt::div([attr::id("bar")], []))]
+#[case(elt::div([], ["a".into()]), elt::div([], ["b".into()]))]
+#[case(elt::text("<br>"), elt::raw("<br>"))]
+#[case(Element::new("br", [], []), elt::br([]))]
+#[case(elt::div([attr::disabled()], []), elt::div([("disabled", "").into()], []))]
+fn should_not_be_equal(#[case] a: Element, #[case] b: Element) {
+    assert_ne!(a, b);
+    assert!(!a.eq_normalized(&b));
+}
+
+#[rstest]
+#[case(
+    elt::div([attr::id("foo"), attr::class(["bar"])], []),
+    elt::div([attr::class(["bar"]), attr::id("foo")], []),
+)]
+#[case(
+    elt::div([], [[elt::p([], []), [elt::span([], [])].into()].into()]),
+    elt::div([], [elt::p([], []), elt::span([], [])]),
+)]
+#[case(
+    elt::div([attr::none()], [elt::none(), elt::br([attr::none()])]),
+    elt::div([], [elt::br([])]),
+)]
+#[case(
+    elt::img([Attribute::new_int("width", 5)]),
+    elt::img([attr::width("5")])
+)]
+#[case(
+    elt::p([], [elt::text("hello "), [elt::none(), "world".into()].into(), "!".into()]),
+    elt::p([], ["hello world!".into()]),
+)]
+fn should_be_equal_after_normalization(#[case] a: Element, #[case] b: Element) {
+    assert_ne!(a, b);
+    assert!(a.eq_normalized(&b));
+    assert!(b.eq_normalized(&a));
+}
+
+#[test]
+fn documents_should_be_comparable() {
+    let a = html([attr::lang("en")], [elt::head([], []), elt::body([], [])]);
+    let b = html(
+        [attr::lang("en")],
+        [[elt::head([], [])].into(), elt::body([], [])],
+    );
+    assert_eq!(a, a.clone());
+    assert_ne!(a, b);
+    assert!(a.eq_normalized(&b));
+    assert_ne!(a, Document::default());
+}
+
+#[test]
+fn attributes_should_be_comparable() {
+    assert_eq!(attr::id("foo"), attr::id("foo".to_string()));
+    assert_eq!(hash(&attr::id("foo")), hash(&attr::id("foo".to_string())));
+    assert_ne!(attr::id("foo"), attr::id("bar"));
+}
+
+#[test]
+fn numeric_attributes_should_be_comparable() {
+    assert_eq!(attr::value_number(3u8), attr::value_number(3i64));
+    assert_eq!(
+        hash(&attr::value_number(3u8)),
+        hash(&attr::value_number(3i64))
+    );
+    assert_eq!(attr::step_number(0.5), attr::step_number(0.5));
+    assert_ne!(attr::step_number(0.5), attr::step_number(0.25));
+    assert_eq!(
+        Attribute::new_display("d", 42),
+        Attribute::new_display("d", "42")
+    );
+    assert_eq!(
+        hash(&Attribute::new_display("d", 42)),
+        hash(&Attribute::new_display("d", "42"))
+    );
+}
+
+fn hash(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[rstest]
+#[case(elt::none(), elt::none())]
+#[case([elt::none(), elt::text("")].into(), elt::none())]
+#[case([elt::none(), elt::br([])].into(), elt::br([]))]
+#[case(
+    [elt::br([]), [elt::hr([]), [elt::br([])].into()].into()].into(),
+    [elt::br([]), elt::hr([]), elt::br([])].into(),
+)]
+#[case(
+    elt::p([], ["a".into(), [elt::none(), "b".into()].into(), elt::br([]), "c".into()]),
+    elt::p([], ["ab".into(), elt::br([]), "c".into()]),
+)]
+#[case(
+    elt::div([attr::none(), attr::id("a"), attr::none()], []),
+    elt::div([attr::id("a")], []),
+)]
+#[case(
+    elt::input([attr::id("a"), attr::name("n"), attr::id("b")]),
+    elt::input([attr::id("b"), attr::name("n")]),
+)]
+#[case(
+    elt::div([attr::class(["a", "b"]), attr::id("x"), attr::class(["b", "c"])], []),
+    elt::div([attr::class(["a", "b", "c"]), attr::id("x")], []),
+)]
+#[case(
+    elt::div([attr::class(["a", "b"]), attr::class_list(fun_html::classes!["b", "c" => true, "d" => false])], []),
+    elt::div([attr::class(["a", "b", "c"])], []),
+)]
+#[case(
+    elt::div([attr::style("color: red; margin: 0"), attr::id("x"), attr::style("MARGIN: 1px;")], []),
+    elt::div([attr::style("color: red; margin: 1px"), attr::id("x")], []),
+)]
+#[case(
+    elt::div([attr::style("color: red !important; --a: 1"), attr::style("color: blue; --A: 2")], []),
+    elt::div([attr::style("color: red !important; --a: 1; --A: 2")], []),
+)]
+#[case(
+    elt::div([attr::style("background: url(\"a;b:c\"); content: 'x;y'"), attr::style(Style::new().width(Length::px(1)))], []),
+    elt::div([attr::style("background: url(\"a;b:c\"); content: 'x;y'; width: 1px")], []),
+)]
+#[case(
+    elt::div([], [elt::script([], "a"), elt::raw("<br>"), elt::raw("<br>")]),
+    elt::div([], [elt::script([], "a"), elt::raw("<br>"), elt::raw("<br>")]),
+)]
+fn should_normalize(#[case] element: Element, #[case] expected: Element) {
+    assert_eq!(element.normalize(), expected);
+}
+
+#[test]
+fn should_normalize_document() {
+    let doc = html(
+        [attr::lang("en"), attr::lang("fr")],
+        [[elt::head([], [])].into(), elt::body([], [])],
+    );
+    assert_eq!(
+        doc.normalize(),
+        html([attr::lang("fr")], [elt::head([], []), elt::body([], [])])
+    );
+}
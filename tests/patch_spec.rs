@@ -0,0 +1,109 @@
+use rstest::rstest;
+
+use fun_html::{attr, elt, patch, Element, Patch};
+
+fn li(key: &'static str) -> Element {
+    elt::li([attr::id(key)], [key.into()])
+}
+
+#[rstest]
+#[case(elt::div([], []), elt::div([], []))]
+#[case(
+    elt::div([attr::id("a"), attr::class(["b"])], ["hello ".into(), "world".into()]),
+    elt::div([attr::class(["b"]), attr::id("a")], ["hello world".into()]),
+)]
+#[case(elt::input([attr::disabled()]), elt::input([("disabled", "").into()]))]
+fn should_not_patch_equivalent_trees(#[case] old: Element, #[case] new: Element) {
+    assert!(patch(&old, &new).is_empty());
+    assert_eq!(patch(&old, &new).to_json(), "[]");
+}
+
+#[rstest]
+#[case(
+    elt::div([], []),
+    elt::span([], []),
+    vec![Patch::Replace { path: vec![0], html: "<span></span>".into() }],
+)]
+#[case(
+    elt::p([], ["a".into()]),
+    elt::p([], ["b".into()]),
+    vec![Patch::Replace { path: vec![0, 0], html: "b".into() }],
+)]
+#[case(
+    elt::div([attr::id("a"), attr::disabled()], []),
+    elt::div([attr::id("a"), attr::class(["c"])], []),
+    vec![
+        Patch::SetAttribute { path: vec![0], name: "class".into(), value: "c".into() },
+        Patch::RemoveAttribute { path: vec![0], name: "disabled".into() },
+    ],
+)]
+#[case(
+    elt::ul([], [li("a"), li("c")]),
+    elt::ul([], [li("a"), li("b"), li("c")]),
+    vec![Patch::InsertChild { path: vec![0], index: 1, html: "<li id=\"b\">b</li>".into() }],
+)]
+#[case(
+    elt::ul([], [li("a"), li("b"), li("c")]),
+    elt::ul([], [li("a"), li("c")]),
+    vec![Patch::RemoveChild { path: vec![0], index: 1 }],
+)]
+#[case(
+    elt::ul([], [li("a"), li("b")]),
+    elt::ul([], [li("b"), li("a")]),
+    vec![
+        Patch::RemoveChild { path: vec![0], index: 0 },
+        Patch::InsertChild { path: vec![0], index: 1, html: "<li id=\"a\">a</li>".into() },
+    ],
+)]
+#[case(
+    elt::ul([], [elt::li([("data-key", "1").into()], ["a".into()])]),
+    elt::ul([], [elt::li([("data-key", "2").into()], ["a".into()])]),
+    vec![Patch::Replace { path: vec![0, 0], html: "<li data-key=\"2\">a</li>".into() }],
+)]
+#[case(
+    elt::ul([], [elt::li([], ["a".into()]), elt::li([], ["b".into()])]),
+    elt::ul([], [elt::li([], ["a".into()]), elt::li([], ["c".into()])]),
+    vec![Patch::Replace { path: vec![0, 1, 0], html: "c".into() }],
+)]
+#[case(
+    elt::div([attr::title("z"), attr::title("a")], []),
+    elt::div([attr::title("a"), attr::title("z")], []),
+    vec![Patch::SetAttribute { path: vec![0], name: "title".into(), value: "a".into() }],
+)]
+#[case(
+    elt::ul([], [elt::li([attr::id("x"), attr::id("a")], ["t".into()])]),
+    elt::ul([], [elt::li([attr::id("a")], ["t".into()])]),
+    vec![Patch::Replace { path: vec![0, 0], html: "<li id=\"a\">t</li>".into() }],
+)]
+fn should_compute_patches(
+    #[case] old: Element,
+    #[case] new: Element,
+    #[case] expected: Vec<Patch>,
+) {
+    assert_eq!(patch(&old, &new).operations(), expected);
+}
+
+#[test]
+fn should_serialize_patches_to_json() {
+    let old = elt::div(
+        [attr::id("x"), attr::disabled()],
+        [elt::p([], ["a".into()]), elt::br([])],
+    );
+    let new = elt::div(
+        [attr::id("y")],
+        [elt::p([], ["\"b\"\n".into()]), elt::hr([])],
+    );
+    assert_eq!(
+        patch(&old, &new).to_json(),
+        r#"[["r",[0],"<div id=\"y\"><p>\"b\"\n</p><hr></div>"]]"#
+    );
+    let old = elt::div(
+        [attr::class(["x"]), attr::disabled()],
+        [elt::p([], ["a".into()]), elt::br([])],
+    );
+    let new = elt::div([attr::class(["y"])], [elt::p([], ["\"b\"\n".into()])]);
+    assert_eq!(
+        patch(&old, &new).to_json(),
+        r#"[["s",[0],"class","y"],["u",[0],"disabled"],["r",[0,0,0],"\"b\"\n"],["d",[0],1]]"#
+    );
+}
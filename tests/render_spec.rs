@@ -1,7 +1,19 @@
+use std::{borrow::Cow, time::Duration};
+
 use rstest::rstest;
 
 use fun_html::{
-    attr::{self, AnchorTarget},
+    attr::{
+        self,
+        aria::{self, Current, HasPopup, Invalid, Orientation, Politeness, Role, Sort, Tristate},
+        css::{self, Color, Length, Style},
+        Allow, AnchorTarget, Autocapitalize, Autocomplete, Capture, ClassList, Command,
+        ContactField, ContentEditable, CrossOrigin, Decoding, Dir, Enctype, EnterKeyHint, Feature,
+        FetchPriority, Field, FormMethod, Href, InputMode, InputType, InvalidSectionName, LinkRel,
+        Loading, Origin, Popover, PopoverTargetAction, Preload, ReferrerPolicy, Sandbox, Scope,
+        Shape, Sizes, Srcset, TrackKind,
+    },
+    classes,
     elt::{self},
     Attribute, Document, Element,
 };
@@ -35,6 +47,90 @@ fn should_render_html_document() {
 #[case(("@keyup.enter", "doSomething").into(), "@keyup.enter=\"doSomething\"")]
 #[case(Attribute::new_unsafe_name("hello".to_string(), "world".to_string()), "hello=\"world\"")]
 #[case(attr::id("foo"), "id=\"foo\"")]
+#[case(attr::title("Close"), "title=\"Close\"")]
+#[case(attr::tabindex(-1), "tabindex=\"-1\"")]
+#[case(attr::hidden(), "hidden")]
+#[case(attr::hidden_until_found(), "hidden=\"until-found\"")]
+#[case(attr::dir(Dir::Ltr), "dir=\"ltr\"")]
+#[case(attr::dir(Dir::Rtl), "dir=\"rtl\"")]
+#[case(attr::dir(Dir::Auto), "dir=\"auto\"")]
+#[case(attr::translate(true), "translate=\"yes\"")]
+#[case(attr::translate(false), "translate=\"no\"")]
+#[case(
+    attr::contenteditable(ContentEditable::True),
+    "contenteditable=\"true\""
+)]
+#[case(
+    attr::contenteditable(ContentEditable::False),
+    "contenteditable=\"false\""
+)]
+#[case(
+    attr::contenteditable(ContentEditable::PlaintextOnly),
+    "contenteditable=\"plaintext-only\""
+)]
+#[case(attr::draggable(true), "draggable=\"true\"")]
+#[case(attr::draggable(false), "draggable=\"false\"")]
+#[case(attr::spellcheck(true), "spellcheck=\"true\"")]
+#[case(attr::spellcheck(false), "spellcheck=\"false\"")]
+#[case(attr::inert(), "inert")]
+#[case(attr::popover(Popover::Auto), "popover=\"auto\"")]
+#[case(attr::popover(Popover::Manual), "popover=\"manual\"")]
+#[case(attr::popover(Popover::Hint), "popover=\"hint\"")]
+#[case(attr::popovertarget("menu"), "popovertarget=\"menu\"")]
+#[case(
+    attr::popovertargetaction(PopoverTargetAction::Toggle),
+    "popovertargetaction=\"toggle\""
+)]
+#[case(
+    attr::popovertargetaction(PopoverTargetAction::Show),
+    "popovertargetaction=\"show\""
+)]
+#[case(
+    attr::popovertargetaction(PopoverTargetAction::Hide),
+    "popovertargetaction=\"hide\""
+)]
+#[case(attr::open(), "open")]
+#[case(attr::commandfor("dialog"), "commandfor=\"dialog\"")]
+#[case(attr::command(Command::ShowModal), "command=\"show-modal\"")]
+#[case(attr::command(Command::Close), "command=\"close\"")]
+#[case(attr::command(Command::RequestClose), "command=\"request-close\"")]
+#[case(attr::command(Command::ShowPopover), "command=\"show-popover\"")]
+#[case(attr::command(Command::HidePopover), "command=\"hide-popover\"")]
+#[case(attr::command(Command::TogglePopover), "command=\"toggle-popover\"")]
+#[case(attr::command(Command::Custom("--rotate".into())), "command=\"--rotate\"")]
+#[case(attr::accesskey("s"), "accesskey=\"s\"")]
+#[case(attr::enterkeyhint(EnterKeyHint::Enter), "enterkeyhint=\"enter\"")]
+#[case(attr::enterkeyhint(EnterKeyHint::Done), "enterkeyhint=\"done\"")]
+#[case(attr::enterkeyhint(EnterKeyHint::Go), "enterkeyhint=\"go\"")]
+#[case(attr::enterkeyhint(EnterKeyHint::Next), "enterkeyhint=\"next\"")]
+#[case(
+    attr::enterkeyhint(EnterKeyHint::Previous),
+    "enterkeyhint=\"previous\""
+)]
+#[case(attr::enterkeyhint(EnterKeyHint::Search), "enterkeyhint=\"search\"")]
+#[case(attr::enterkeyhint(EnterKeyHint::Send), "enterkeyhint=\"send\"")]
+#[case(attr::inputmode(InputMode::None), "inputmode=\"none\"")]
+#[case(attr::inputmode(InputMode::Text), "inputmode=\"text\"")]
+#[case(attr::inputmode(InputMode::Decimal), "inputmode=\"decimal\"")]
+#[case(attr::inputmode(InputMode::Numeric), "inputmode=\"numeric\"")]
+#[case(attr::inputmode(InputMode::Tel), "inputmode=\"tel\"")]
+#[case(attr::inputmode(InputMode::Search), "inputmode=\"search\"")]
+#[case(attr::inputmode(InputMode::Email), "inputmode=\"email\"")]
+#[case(attr::inputmode(InputMode::Url), "inputmode=\"url\"")]
+#[case(attr::autocapitalize(Autocapitalize::None), "autocapitalize=\"none\"")]
+#[case(
+    attr::autocapitalize(Autocapitalize::Sentences),
+    "autocapitalize=\"sentences\""
+)]
+#[case(
+    attr::autocapitalize(Autocapitalize::Words),
+    "autocapitalize=\"words\""
+)]
+#[case(
+    attr::autocapitalize(Autocapitalize::Characters),
+    "autocapitalize=\"characters\""
+)]
+#[case(attr::nonce("r4nd0m"), "nonce=\"r4nd0m\"")]
 #[case(attr::class(["foo"]), "class=\"foo\"")]
 #[case(attr::class(["foo", "bar"]), "class=\"foo bar\"")]
 #[case(attr::href("foo"), "href=\"foo\"")]
@@ -55,6 +151,76 @@ fn should_render_html_document() {
 #[case(attr::type_hidden(), "type=\"hidden\"")]
 #[case(attr::type_reset(), "type=\"reset\"")]
 #[case(attr::type_submit(), "type=\"submit\"")]
+#[case(attr::input_type(InputType::Hidden), "type=\"hidden\"")]
+#[case(attr::input_type(InputType::Text), "type=\"text\"")]
+#[case(attr::input_type(InputType::Search), "type=\"search\"")]
+#[case(attr::input_type(InputType::Tel), "type=\"tel\"")]
+#[case(attr::input_type(InputType::Url), "type=\"url\"")]
+#[case(attr::input_type(InputType::Email), "type=\"email\"")]
+#[case(attr::input_type(InputType::Password), "type=\"password\"")]
+#[case(attr::input_type(InputType::Date), "type=\"date\"")]
+#[case(attr::input_type(InputType::Month), "type=\"month\"")]
+#[case(attr::input_type(InputType::Week), "type=\"week\"")]
+#[case(attr::input_type(InputType::Time), "type=\"time\"")]
+#[case(attr::input_type(InputType::DatetimeLocal), "type=\"datetime-local\"")]
+#[case(attr::input_type(InputType::Number), "type=\"number\"")]
+#[case(attr::input_type(InputType::Range), "type=\"range\"")]
+#[case(attr::input_type(InputType::Color), "type=\"color\"")]
+#[case(attr::input_type(InputType::Checkbox), "type=\"checkbox\"")]
+#[case(attr::input_type(InputType::Radio), "type=\"radio\"")]
+#[case(attr::input_type(InputType::File), "type=\"file\"")]
+#[case(attr::input_type(InputType::Submit), "type=\"submit\"")]
+#[case(attr::input_type(InputType::Image), "type=\"image\"")]
+#[case(attr::input_type(InputType::Reset), "type=\"reset\"")]
+#[case(attr::input_type(InputType::Button), "type=\"button\"")]
+#[case(attr::crossorigin(CrossOrigin::Anonymous), "crossorigin=\"anonymous\"")]
+#[case(
+    attr::crossorigin(CrossOrigin::UseCredentials),
+    "crossorigin=\"use-credentials\""
+)]
+#[case(attr::method(FormMethod::Get), "method=\"get\"")]
+#[case(attr::method(FormMethod::Post), "method=\"post\"")]
+#[case(attr::method(FormMethod::Dialog), "method=\"dialog\"")]
+#[case(attr::formmethod(FormMethod::Post), "formmethod=\"post\"")]
+#[case(attr::link_rel([]), "rel=\"\"")]
+#[case(attr::link_rel([LinkRel::Stylesheet]), "rel=\"stylesheet\"")]
+#[case(attr::link_rel([LinkRel::NoOpener, LinkRel::NoReferrer]), "rel=\"noopener noreferrer\"")]
+#[case(attr::link_rel([LinkRel::Next, LinkRel::NoFollow, LinkRel::Next]), "rel=\"next nofollow\"")]
+#[case(attr::link_rel([LinkRel::Alternate]), "rel=\"alternate\"")]
+#[case(attr::link_rel([LinkRel::Author]), "rel=\"author\"")]
+#[case(attr::link_rel([LinkRel::Bookmark]), "rel=\"bookmark\"")]
+#[case(attr::link_rel([LinkRel::Canonical]), "rel=\"canonical\"")]
+#[case(attr::link_rel([LinkRel::DnsPrefetch]), "rel=\"dns-prefetch\"")]
+#[case(attr::link_rel([LinkRel::External]), "rel=\"external\"")]
+#[case(attr::link_rel([LinkRel::Help]), "rel=\"help\"")]
+#[case(attr::link_rel([LinkRel::Icon]), "rel=\"icon\"")]
+#[case(attr::link_rel([LinkRel::License]), "rel=\"license\"")]
+#[case(attr::link_rel([LinkRel::Manifest]), "rel=\"manifest\"")]
+#[case(attr::link_rel([LinkRel::Me]), "rel=\"me\"")]
+#[case(attr::link_rel([LinkRel::ModulePreload]), "rel=\"modulepreload\"")]
+#[case(attr::link_rel([LinkRel::Next]), "rel=\"next\"")]
+#[case(attr::link_rel([LinkRel::NoFollow]), "rel=\"nofollow\"")]
+#[case(attr::link_rel([LinkRel::NoOpener]), "rel=\"noopener\"")]
+#[case(attr::link_rel([LinkRel::NoReferrer]), "rel=\"noreferrer\"")]
+#[case(attr::link_rel([LinkRel::Opener]), "rel=\"opener\"")]
+#[case(attr::link_rel([LinkRel::Pingback]), "rel=\"pingback\"")]
+#[case(attr::link_rel([LinkRel::Preconnect]), "rel=\"preconnect\"")]
+#[case(attr::link_rel([LinkRel::Prefetch]), "rel=\"prefetch\"")]
+#[case(attr::link_rel([LinkRel::Preload]), "rel=\"preload\"")]
+#[case(attr::link_rel([LinkRel::Prev]), "rel=\"prev\"")]
+#[case(attr::link_rel([LinkRel::PrivacyPolicy]), "rel=\"privacy-policy\"")]
+#[case(attr::link_rel([LinkRel::Search]), "rel=\"search\"")]
+#[case(attr::link_rel([LinkRel::Stylesheet]), "rel=\"stylesheet\"")]
+#[case(attr::link_rel([LinkRel::Tag]), "rel=\"tag\"")]
+#[case(attr::link_rel([LinkRel::TermsOfService]), "rel=\"terms-of-service\"")]
+#[case(attr::type_url(), "type=\"url\"")]
+#[case(attr::type_search(), "type=\"search\"")]
+#[case(attr::type_color(), "type=\"color\"")]
+#[case(attr::type_time(), "type=\"time\"")]
+#[case(attr::type_week(), "type=\"week\"")]
+#[case(attr::type_datetime_local(), "type=\"datetime-local\"")]
+#[case(attr::type_image(), "type=\"image\"")]
+#[case(attr::type_button(), "type=\"button\"")]
 #[case(attr::integrity("foo"), "integrity=\"foo\"")]
 #[case(attr::defer(), "defer")]
 #[case(attr::async_(), "async")]
@@ -82,14 +248,84 @@ fn should_render_html_document() {
 #[case(attr::action("something"), "action=\"something\"")]
 #[case(attr::method_get(), "method=\"get\"")]
 #[case(attr::method_post(), "method=\"post\"")]
+#[case(attr::method_dialog(), "method=\"dialog\"")]
+#[case(
+    attr::enctype(Enctype::UrlEncoded),
+    "enctype=\"application/x-www-form-urlencoded\""
+)]
+#[case(attr::enctype(Enctype::Multipart), "enctype=\"multipart/form-data\"")]
+#[case(attr::enctype(Enctype::TextPlain), "enctype=\"text/plain\"")]
+#[case(attr::novalidate(), "novalidate")]
+#[case(attr::form("signup"), "form=\"signup\"")]
+#[case(attr::formaction("/preview"), "formaction=\"/preview\"")]
+#[case(attr::formmethod_get(), "formmethod=\"get\"")]
+#[case(attr::formmethod_post(), "formmethod=\"post\"")]
+#[case(attr::formmethod_dialog(), "formmethod=\"dialog\"")]
+#[case(
+    attr::formenctype(Enctype::Multipart),
+    "formenctype=\"multipart/form-data\""
+)]
+#[case(attr::formnovalidate(), "formnovalidate")]
+#[case(attr::formtarget(AnchorTarget::Blank), "formtarget=\"_blank\"")]
+#[case(attr::formtarget(AnchorTarget::Frame("preview".into())), "formtarget=\"preview\"")]
+#[case(attr::checked(), "checked")]
+#[case(attr::selected(), "selected")]
+#[case(attr::readonly(), "readonly")]
+#[case(attr::list("colors"), "list=\"colors\"")]
+#[case(attr::size(20), "size=\"20\"")]
+#[case(attr::accept("image/*,.pdf"), "accept=\"image/*,.pdf\"")]
+#[case(attr::capture(Capture::User), "capture=\"user\"")]
+#[case(attr::capture(Capture::Environment), "capture=\"environment\"")]
+#[case(attr::dirname("comment.dir"), "dirname=\"comment.dir\"")]
+#[case(attr::step("0.01"), "step=\"0.01\"")]
+#[case(attr::step("any"), "step=\"any\"")]
+#[case(attr::low("10"), "low=\"10\"")]
+#[case(attr::high("90"), "high=\"90\"")]
+#[case(attr::optimum("50"), "optimum=\"50\"")]
 #[case(attr::for_("foo"), "for=\"foo\"")]
 #[case(attr::value("hello"), "value=\"hello\"")]
+#[case(attr::datetime("2025-01-25"), "datetime=\"2025-01-25\"")]
+#[case(attr::cite("https://example.com"), "cite=\"https://example.com\"")]
 #[case(attr::required(), "required")]
 #[case(attr::disabled(), "disabled")]
 #[case(attr::autofocus(), "autofocus")]
 #[case(attr::autocomplete("email"), "autocomplete=\"email\"")]
 #[case(attr::autocomplete_on(), "autocomplete=\"on\"")]
 #[case(attr::autocomplete_off(), "autocomplete=\"off\"")]
+#[case(attr::autocomplete(Field::Name), "autocomplete=\"name\"")]
+#[case(attr::autocomplete(ContactField::Email), "autocomplete=\"email\"")]
+#[case(
+    attr::autocomplete(Field::Contact(ContactField::Tel)),
+    "autocomplete=\"tel\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::new(Field::CurrentPassword)),
+    "autocomplete=\"current-password\""
+)]
+#[case(attr::autocomplete(Autocomplete::new(ContactField::Email).section("checkout").unwrap().shipping()), "autocomplete=\"section-checkout shipping email\"")]
+#[case(attr::autocomplete(Autocomplete::new(Field::PostalCode).billing()), "autocomplete=\"billing postal-code\"")]
+#[case(attr::autocomplete(ContactField::Tel.mobile()), "autocomplete=\"mobile tel\"")]
+#[case(attr::autocomplete(ContactField::TelExtension.work()), "autocomplete=\"work tel-extension\"")]
+#[case(attr::autocomplete(ContactField::Email.home()), "autocomplete=\"home email\"")]
+#[case(attr::autocomplete(ContactField::Tel.fax()), "autocomplete=\"fax tel\"")]
+#[case(attr::autocomplete(ContactField::Tel.pager()), "autocomplete=\"pager tel\"")]
+#[case(attr::autocomplete(Autocomplete::new(Field::Username).webauthn()), "autocomplete=\"username webauthn\"")]
+#[case(
+    attr::autocomplete(ContactField::Tel.mobile().webauthn().billing().section("a").unwrap()),
+    "autocomplete=\"section-a billing mobile tel webauthn\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::new(Field::CcExpMonth)),
+    "autocomplete=\"cc-exp-month\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::new(Field::AddressLevel2)),
+    "autocomplete=\"address-level2\""
+)]
+#[case(
+    attr::autocomplete(Autocomplete::new(Field::OneTimeCode)),
+    "autocomplete=\"one-time-code\""
+)]
 #[case(attr::pattern("foobar"), "pattern=\"foobar\"")]
 #[case(attr::min("value"), "min=\"value\"")]
 #[case(attr::max("value"), "max=\"value\"")]
@@ -100,13 +336,292 @@ fn should_render_html_document() {
 #[case(attr::multiple(), "multiple")]
 #[case(attr::placeholder("hello"), "placeholder=\"hello\"")]
 #[case(attr::rows(10), "rows=\"10\"")]
+#[case(attr::colspan(2), "colspan=\"2\"")]
+#[case(attr::rowspan(0), "rowspan=\"0\"")]
+#[case(attr::rowspan(3), "rowspan=\"3\"")]
+#[case(attr::span(2), "span=\"2\"")]
+#[case(attr::scope(Scope::Row), "scope=\"row\"")]
+#[case(attr::scope(Scope::Col), "scope=\"col\"")]
+#[case(attr::scope(Scope::RowGroup), "scope=\"rowgroup\"")]
+#[case(attr::scope(Scope::ColGroup), "scope=\"colgroup\"")]
+#[case(attr::headers("name"), "headers=\"name\"")]
+#[case(attr::headers(["name", "q1"]), "headers=\"name q1\"")]
+#[case(attr::abbr("Qty"), "abbr=\"Qty\"")]
 #[case(attr::cols(10), "cols=\"10\"")]
 #[case(attr::style("width: 5px"), "style=\"width: 5px\"")]
+#[case(attr::sandbox(Sandbox::new()), "sandbox=\"\"")]
+#[case(attr::sandbox(Sandbox::new().allow_scripts()), "sandbox=\"allow-scripts\"")]
+#[case(attr::sandbox(Sandbox::new().allow_scripts().allow_scripts()), "sandbox=\"allow-scripts\"")]
+#[case(
+    attr::sandbox(
+        Sandbox::new()
+            .allow_top_navigation_to_custom_protocols()
+            .allow_top_navigation_by_user_activation()
+            .allow_top_navigation()
+            .allow_storage_access_by_user_activation()
+            .allow_scripts()
+            .allow_same_origin()
+            .allow_presentation()
+            .allow_popups_to_escape_sandbox()
+            .allow_popups()
+            .allow_pointer_lock()
+            .allow_orientation_lock()
+            .allow_modals()
+            .allow_forms()
+            .allow_downloads()
+    ),
+    "sandbox=\"allow-downloads allow-forms allow-modals allow-orientation-lock allow-pointer-lock allow-popups allow-popups-to-escape-sandbox allow-presentation allow-same-origin allow-scripts allow-storage-access-by-user-activation allow-top-navigation allow-top-navigation-by-user-activation allow-top-navigation-to-custom-protocols\""
+)]
+#[case(attr::allow(Allow::new()), "allow=\"\"")]
+#[case(attr::allow(Allow::new().feature(Feature::Fullscreen)), "allow=\"fullscreen\"")]
+#[case(
+    attr::allow(Allow::new().feature(Feature::ClipboardWrite).feature(Feature::PictureInPicture)),
+    "allow=\"clipboard-write; picture-in-picture\""
+)]
+#[case(
+    attr::allow(Allow::new().feature_for(Feature::Geolocation, [Origin::Src, Origin::Self_, Origin::Url("https://a.com".into())])),
+    "allow=\"geolocation 'src' 'self' https://a.com\""
+)]
+#[case(attr::allow(Allow::new().feature_for(Feature::Camera, [Origin::None])), "allow=\"camera 'none'\"")]
+#[case(attr::allow(Allow::new().feature_for(Feature::Other("ch-ua".into()), [Origin::Any])), "allow=\"ch-ua *\"")]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::NoReferrer),
+    "referrerpolicy=\"no-referrer\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::NoReferrerWhenDowngrade),
+    "referrerpolicy=\"no-referrer-when-downgrade\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::Origin),
+    "referrerpolicy=\"origin\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::OriginWhenCrossOrigin),
+    "referrerpolicy=\"origin-when-cross-origin\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::SameOrigin),
+    "referrerpolicy=\"same-origin\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::StrictOrigin),
+    "referrerpolicy=\"strict-origin\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::StrictOriginWhenCrossOrigin),
+    "referrerpolicy=\"strict-origin-when-cross-origin\""
+)]
+#[case(
+    attr::referrerpolicy(ReferrerPolicy::UnsafeUrl),
+    "referrerpolicy=\"unsafe-url\""
+)]
+#[case(attr::srcdoc(elt::p([], ["hello".into()])), "srcdoc=\"&lt;p&gt;hello&lt;/p&gt;\"")]
+#[case(attr::srcdoc(elt::a([attr::href("/?a=1&b=2")], [])), "srcdoc=\"&lt;a href=&quot;/?a=1&amp;amp;b=2&quot;&gt;&lt;/a&gt;\"")]
+#[case(
+    attr::srcdoc(Document::default()),
+    "srcdoc=\"&lt;!DOCTYPE html&gt;\n&lt;html&gt;&lt;head&gt;&lt;/head&gt;&lt;body&gt;&lt;/body&gt;&lt;/html&gt;\""
+)]
+#[case(attr::data("/movie.swf"), "data=\"/movie.swf\"")]
+#[case(attr::controls(), "controls")]
+#[case(attr::autoplay(), "autoplay")]
+#[case(attr::muted(), "muted")]
+#[case(attr::loop_(), "loop")]
+#[case(attr::playsinline(), "playsinline")]
+#[case(attr::poster("/poster.jpg"), "poster=\"/poster.jpg\"")]
+#[case(attr::preload(Preload::None), "preload=\"none\"")]
+#[case(attr::preload(Preload::Metadata), "preload=\"metadata\"")]
+#[case(attr::preload(Preload::Auto), "preload=\"auto\"")]
+#[case(attr::loading(Loading::Lazy), "loading=\"lazy\"")]
+#[case(attr::loading(Loading::Eager), "loading=\"eager\"")]
+#[case(attr::decoding(Decoding::Sync), "decoding=\"sync\"")]
+#[case(attr::decoding(Decoding::Async), "decoding=\"async\"")]
+#[case(attr::decoding(Decoding::Auto), "decoding=\"auto\"")]
+#[case(attr::fetchpriority(FetchPriority::High), "fetchpriority=\"high\"")]
+#[case(attr::fetchpriority(FetchPriority::Low), "fetchpriority=\"low\"")]
+#[case(attr::fetchpriority(FetchPriority::Auto), "fetchpriority=\"auto\"")]
+#[case(attr::srcset(Srcset::new()), "srcset=\"\"")]
+#[case(attr::srcset(Srcset::new().width("a.jpg", 400)), "srcset=\"a.jpg 400w\"")]
+#[case(attr::srcset(Srcset::new().width("a.jpg", 400).width("b.jpg", 800)), "srcset=\"a.jpg 400w, b.jpg 800w\"")]
+#[case(attr::srcset(Srcset::new().density("a.jpg", 1.0).density("b.jpg", 1.5)), "srcset=\"a.jpg 1x, b.jpg 1.5x\"")]
+#[case(attr::srcset(Srcset::new().density("my photo.jpg", 2.0)), "srcset=\"my%20photo.jpg 2x\"")]
+#[case(attr::srcset(Srcset::new().width(",a,b,", 10)), "srcset=\"%2Ca,b%2C 10w\"")]
+#[case(attr::sizes(Sizes::new("100vw")), "sizes=\"100vw\"")]
+#[case(
+    attr::sizes(Sizes::new("100vw").media("(min-width: 1200px)", "33vw").media("(min-width: 800px)", "50vw")),
+    "sizes=\"(min-width: 1200px) 33vw, (min-width: 800px) 50vw, 100vw\""
+)]
+#[case(
+    attr::media("(prefers-color-scheme: dark)"),
+    "media=\"(prefers-color-scheme: dark)\""
+)]
+#[case(attr::kind(TrackKind::Subtitles), "kind=\"subtitles\"")]
+#[case(attr::kind(TrackKind::Captions), "kind=\"captions\"")]
+#[case(attr::kind(TrackKind::Descriptions), "kind=\"descriptions\"")]
+#[case(attr::kind(TrackKind::Chapters), "kind=\"chapters\"")]
+#[case(attr::kind(TrackKind::Metadata), "kind=\"metadata\"")]
+#[case(attr::srclang("en"), "srclang=\"en\"")]
+#[case(attr::label("English"), "label=\"English\"")]
+#[case(attr::default(), "default")]
+#[case(attr::shape(Shape::Default), "shape=\"default\"")]
+#[case(attr::shape(Shape::Rect), "shape=\"rect\"")]
+#[case(attr::shape(Shape::Circle), "shape=\"circle\"")]
+#[case(attr::shape(Shape::Poly), "shape=\"poly\"")]
+#[case(attr::coords([0, 0, 10, 20]), "coords=\"0,0,10,20\"")]
+#[case(attr::usemap("planets"), "usemap=\"#planets\"")]
+#[case(attr::usemap(String::from("solar system")), "usemap=\"#solar system\"")]
 #[case(attr::role_str("menu"), "role=\"menu\"")]
+#[case(attr::role(Role::Menu), "role=\"menu\"")]
+#[case(attr::role(Role::AlertDialog), "role=\"alertdialog\"")]
+#[case(attr::role(Role::MenuItemCheckbox), "role=\"menuitemcheckbox\"")]
+#[case(aria::aria_expanded(true), "aria-expanded=\"true\"")]
+#[case(aria::aria_expanded(false), "aria-expanded=\"false\"")]
+#[case(aria::aria_hidden(true), "aria-hidden=\"true\"")]
+#[case(aria::aria_checked(false), "aria-checked=\"false\"")]
+#[case(aria::aria_checked(Tristate::Mixed), "aria-checked=\"mixed\"")]
+#[case(aria::aria_pressed(Tristate::True), "aria-pressed=\"true\"")]
+#[case(aria::aria_controls("menu"), "aria-controls=\"menu\"")]
+#[case(aria::aria_controls(["a", "b"]), "aria-controls=\"a b\"")]
+#[case(aria::aria_describedby(vec!["a".to_string(), "b".to_string()]), "aria-describedby=\"a b\"")]
+#[case(aria::aria_labelledby("title"), "aria-labelledby=\"title\"")]
+#[case(aria::aria_owns(&["a", "b"][..]), "aria-owns=\"a b\"")]
+#[case(
+    aria::aria_activedescendant("opt-1"),
+    "aria-activedescendant=\"opt-1\""
+)]
+#[case(aria::aria_live(Politeness::Polite), "aria-live=\"polite\"")]
+#[case(aria::aria_live(Politeness::Assertive), "aria-live=\"assertive\"")]
+#[case(aria::aria_current(Current::Page), "aria-current=\"page\"")]
+#[case(aria::aria_haspopup(HasPopup::Listbox), "aria-haspopup=\"listbox\"")]
+#[case(aria::aria_invalid(Invalid::Spelling), "aria-invalid=\"spelling\"")]
+#[case(aria::aria_sort(Sort::Descending), "aria-sort=\"descending\"")]
+#[case(
+    aria::aria_orientation(Orientation::Vertical),
+    "aria-orientation=\"vertical\""
+)]
+#[case(aria::aria_label("Close"), "aria-label=\"Close\"")]
+#[case(aria::aria_level(2), "aria-level=\"2\"")]
+#[case(aria::aria_setsize(-1), "aria-setsize=\"-1\"")]
+#[case(aria::aria_valuenow(0.5), "aria-valuenow=\"0.5\"")]
+#[case(aria::aria_valuemax(100.0), "aria-valuemax=\"100\"")]
+#[case(Attribute::new_number("n", 42u8), "n=\"42\"")]
+#[case(Attribute::new_number("n", -7i64), "n=\"-7\"")]
+#[case(
+    Attribute::new_number("n", i128::MIN),
+    "n=\"-170141183460469231731687303715884105728\""
+)]
+#[case(Attribute::new_number("n", u64::MAX), "n=\"18446744073709551615\"")]
+#[case(Attribute::new_number("n", 0.1f32), "n=\"0.1\"")]
+#[case(Attribute::new_number("n", -2.5f64), "n=\"-2.5\"")]
+#[case(Attribute::new_number("n", 3.0), "n=\"3\"")]
+#[case(Attribute::new_number("n", 1e21), "n=\"1000000000000000000000\"")]
+#[case(Attribute::new_number("n", 1e-7), "n=\"0.0000001\"")]
+#[case(attr::min_number(-10), "min=\"-10\"")]
+#[case(attr::max_number(99.5), "max=\"99.5\"")]
+#[case(attr::step_number(0.01), "step=\"0.01\"")]
+#[case(attr::value_number(3u32), "value=\"3\"")]
+#[case(Attribute::new_display("d", 'x'), "d=\"x\"")]
+#[case(attr::datetime("2024-03-01"), "datetime=\"2024-03-01\"")]
+#[case(attr::datetime(Duration::from_secs(0)), "datetime=\"PT0S\"")]
+#[case(
+    attr::datetime(Duration::from_millis(93_784_005)),
+    "datetime=\"P1DT2H3M4.005S\""
+)]
+#[case(attr::datetime(Duration::from_secs(60)), "datetime=\"PT1M\"")]
+#[case(attr::min_date("2024-01-01"), "min=\"2024-01-01\"")]
+#[case(attr::href(Href::path("/")), "href=\"/\"")]
+#[case(attr::style(Style::new()), "style=\"\"")]
+#[case(attr::class_list(ClassList::new()), "class=\"\"")]
+#[case(attr::class_list(classes![]), "class=\"\"")]
+#[case(
+    attr::class_list(classes!["a", "b" => false, "c d" => true, String::from("a"), Cow::from("e"),]),
+    "class=\"a c d e\""
+)]
+#[case(
+    attr::class_list(classes![Some("x"), None::<&str>, ("y", false), ("z", true)]),
+    "class=\"x z\""
+)]
+#[case(["  p-2 ", "m-1\tp-2"].into_iter().collect::<ClassList>().into(), "class=\"p-2 m-1\"")]
+#[case(
+    attr::style(Style::new().display(css::DisplayValue::InlineBlock).width(Length::px(10)).height(Length::AUTO)),
+    "style=\"display: inline-block; width: 10px; height: auto\""
+)]
+#[case(
+    attr::style(Style::new().margin_xy(Length::em(0.5), Length::AUTO).padding(Length::vw(-1.25))),
+    "style=\"margin: 0.5em auto; padding: -1.25vw\""
+)]
+#[case(
+    attr::style(Style::new().color(Color::hex(0x0a0b0c)).background_color(Color::rgba(1, 2, 3, 0.5))),
+    "style=\"color: #0a0b0c; background-color: rgb(1 2 3 / 0.5)\""
+)]
+#[case(
+    attr::style(Style::new().color(Color::named("red")).color(Color::TRANSPARENT)),
+    "style=\"color: transparent\""
+)]
+#[case(
+    attr::style(Style::new().property("font-family", "\"Fira Sans\", serif")),
+    "style=\"font-family: &quot;Fira Sans&quot;, serif\""
+)]
+#[case(
+    attr::style(Style::new().property("content", "'a;b}' \"/*\\\" '\"x")),
+    "style=\"content: 'a;b}' &quot;/*\\5c &quot; \\27 \\22 x\""
+)]
+#[case(
+    attr::style(Style::new().property("color", "red !important")),
+    "style=\"color: red !important\""
+)]
+#[case(
+    attr::style(Style::new().custom_property("--x", "1}</style><script>/**/")),
+    "style=\"--x: 1\\7d \\3c /style\\3e \\3c script\\3e /\\2a */\""
+)]
+#[case(attr::href(Href::path("/a b/ü?#")), "href=\"/a%20b/%C3%BC%3F%23\"")]
+#[case(
+    attr::href(Href::path("javascript:alert(1)")),
+    "href=\"javascript%3Aalert(1)\""
+)]
+#[case(attr::href(Href::path("//evil.com")), "href=\"/%2Fevil.com\"")]
+#[case(attr::href(Href::path("\\evil.com")), "href=\"%5Cevil.com\"")]
+#[case(attr::href(Href::path("/\\evil.com")), "href=\"/%5Cevil.com\"")]
+#[case(attr::href(Href::path("/a:b/c:d")), "href=\"/a:b/c:d\"")]
+#[case(attr::href(Href::path("a/b//c")), "href=\"a/b//c\"")]
+#[case(
+    attr::href(Href::path("/search").query("q", "\"><script>&x=1#")),
+    "href=\"/search?q=%22%3E%3Cscript%3E%26x%3D1%23\""
+)]
+#[case(
+    attr::action(Href::path("/s").query("a b", "c+d").query("n", 1.5)),
+    "action=\"/s?a+b=c%2Bd&amp;n=1.5\""
+)]
+#[case(attr::src(Href::path("/img").query("", "")), "src=\"/img?=\"")]
+#[case(attr::href(Href::from("/a#x").query("k", 1)), "href=\"/a?k=1#x\"")]
+#[case(attr::href(Href::from("/a?b=1#x?y").query("k", 1)), "href=\"/a?b=1&amp;k=1#x?y\"")]
+#[case(attr::href(Href::from("#top").query("k", 1)), "href=\"?k=1#top\"")]
+#[case(attr::cite("https://example.com".to_string()), "cite=\"https://example.com\"")]
+#[case(attr::max_date("12:00".to_string()), "max=\"12:00\"")]
+#[case(
+    Attribute::new_display("d", "a \"b\" & <c>"),
+    "d=\"a &quot;b&quot; &amp; &lt;c&gt;\""
+)]
 fn should_render_attribute(#[case] attr: Attribute, #[case] expected: &str) {
     assert_eq!(attr.to_string(), expected);
 }
 
+#[test]
+fn url_attributes_should_accept_any_string() {
+    fn link<S: Into<Cow<'static, str>>>(url: S) -> Element {
+        elt::a([attr::href(url)], [])
+    }
+    assert_eq!(link("/a").to_string(), r#"<a href="/a"></a>"#);
+    assert_eq!(
+        link(Href::path("/a b")).to_string(),
+        r#"<a href="/a%20b"></a>"#
+    );
+    assert_eq!(
+        elt::link_stylesheet(String::from("/a.css")).to_string(),
+        r#"<link rel="stylesheet" href="/a.css">"#
+    );
+}
+
 #[rstest]
 #[case(elt::none(), "")]
 #[case([elt::div([], []), elt::div([], [])].into(), "<div></div><div></div>")]
@@ -159,6 +674,61 @@ fn should_render_attribute(#[case] attr: Attribute, #[case] expected: &str) {
 #[case(elt::hr([("foo", "bar").into()]), "<hr foo=\"bar\">")]
 #[case(elt::small([("foo", "bar").into()], ["hello".into()]), "<small foo=\"bar\">hello</small>")]
 #[case(elt::span([("foo", "bar").into()], ["hello".into()]), "<span foo=\"bar\">hello</span>")]
+#[case(elt::datalist([("foo", "bar").into()], ["hello".into()]), "<datalist foo=\"bar\">hello</datalist>")]
+#[case(elt::optgroup([("foo", "bar").into()], ["hello".into()]), "<optgroup foo=\"bar\">hello</optgroup>")]
+#[case(elt::output([("foo", "bar").into()], ["hello".into()]), "<output foo=\"bar\">hello</output>")]
+#[case(elt::progress([("foo", "bar").into()], ["hello".into()]), "<progress foo=\"bar\">hello</progress>")]
+#[case(elt::meter([("foo", "bar").into()], ["hello".into()]), "<meter foo=\"bar\">hello</meter>")]
+#[case(elt::caption([("foo", "bar").into()], ["hello".into()]), "<caption foo=\"bar\">hello</caption>")]
+#[case(elt::colgroup([("foo", "bar").into()], [elt::col([])]), "<colgroup foo=\"bar\"><col></colgroup>")]
+#[case(elt::col([("foo", "bar").into()]), "<col foo=\"bar\">")]
+#[case(elt::iframe([("foo", "bar").into()]), "<iframe foo=\"bar\"></iframe>")]
+#[case(elt::embed([("foo", "bar").into()]), "<embed foo=\"bar\">")]
+#[case(elt::object([("foo", "bar").into()], ["hello".into()]), "<object foo=\"bar\">hello</object>")]
+#[case(elt::noscript([("foo", "bar").into()], ["hello".into()]), "<noscript foo=\"bar\">hello</noscript>")]
+#[case(elt::picture([("foo", "bar").into()], ["hello".into()]), "<picture foo=\"bar\">hello</picture>")]
+#[case(elt::figure([("foo", "bar").into()], ["hello".into()]), "<figure foo=\"bar\">hello</figure>")]
+#[case(elt::figcaption([("foo", "bar").into()], ["hello".into()]), "<figcaption foo=\"bar\">hello</figcaption>")]
+#[case(elt::video([("foo", "bar").into()], ["hello".into()]), "<video foo=\"bar\">hello</video>")]
+#[case(elt::audio([("foo", "bar").into()], ["hello".into()]), "<audio foo=\"bar\">hello</audio>")]
+#[case(elt::canvas([("foo", "bar").into()], ["hello".into()]), "<canvas foo=\"bar\">hello</canvas>")]
+#[case(elt::map([("foo", "bar").into()], ["hello".into()]), "<map foo=\"bar\">hello</map>")]
+#[case(elt::source([("foo", "bar").into()]), "<source foo=\"bar\">")]
+#[case(elt::track([("foo", "bar").into()]), "<track foo=\"bar\">")]
+#[case(elt::area([("foo", "bar").into()]), "<area foo=\"bar\">")]
+#[case(elt::details([("foo", "bar").into()], ["hello".into()]), "<details foo=\"bar\">hello</details>")]
+#[case(elt::summary([("foo", "bar").into()], ["hello".into()]), "<summary foo=\"bar\">hello</summary>")]
+#[case(elt::dialog([("foo", "bar").into()], ["hello".into()]), "<dialog foo=\"bar\">hello</dialog>")]
+#[case(elt::menu([("foo", "bar").into()], ["hello".into()]), "<menu foo=\"bar\">hello</menu>")]
+#[case(elt::strong([("foo", "bar").into()], ["hello".into()]), "<strong foo=\"bar\">hello</strong>")]
+#[case(elt::em([("foo", "bar").into()], ["hello".into()]), "<em foo=\"bar\">hello</em>")]
+#[case(elt::b([("foo", "bar").into()], ["hello".into()]), "<b foo=\"bar\">hello</b>")]
+#[case(elt::i([("foo", "bar").into()], ["hello".into()]), "<i foo=\"bar\">hello</i>")]
+#[case(elt::u([("foo", "bar").into()], ["hello".into()]), "<u foo=\"bar\">hello</u>")]
+#[case(elt::s([("foo", "bar").into()], ["hello".into()]), "<s foo=\"bar\">hello</s>")]
+#[case(elt::code([("foo", "bar").into()], ["hello".into()]), "<code foo=\"bar\">hello</code>")]
+#[case(elt::kbd([("foo", "bar").into()], ["hello".into()]), "<kbd foo=\"bar\">hello</kbd>")]
+#[case(elt::samp([("foo", "bar").into()], ["hello".into()]), "<samp foo=\"bar\">hello</samp>")]
+#[case(elt::var([("foo", "bar").into()], ["hello".into()]), "<var foo=\"bar\">hello</var>")]
+#[case(elt::mark([("foo", "bar").into()], ["hello".into()]), "<mark foo=\"bar\">hello</mark>")]
+#[case(elt::abbr([("foo", "bar").into()], ["hello".into()]), "<abbr foo=\"bar\">hello</abbr>")]
+#[case(elt::cite([("foo", "bar").into()], ["hello".into()]), "<cite foo=\"bar\">hello</cite>")]
+#[case(elt::q([("foo", "bar").into()], ["hello".into()]), "<q foo=\"bar\">hello</q>")]
+#[case(elt::dfn([("foo", "bar").into()], ["hello".into()]), "<dfn foo=\"bar\">hello</dfn>")]
+#[case(elt::sub([("foo", "bar").into()], ["hello".into()]), "<sub foo=\"bar\">hello</sub>")]
+#[case(elt::sup([("foo", "bar").into()], ["hello".into()]), "<sup foo=\"bar\">hello</sup>")]
+#[case(elt::time([("foo", "bar").into()], ["hello".into()]), "<time foo=\"bar\">hello</time>")]
+#[case(elt::data([("foo", "bar").into()], ["hello".into()]), "<data foo=\"bar\">hello</data>")]
+#[case(elt::bdi([("foo", "bar").into()], ["hello".into()]), "<bdi foo=\"bar\">hello</bdi>")]
+#[case(elt::bdo([("foo", "bar").into()], ["hello".into()]), "<bdo foo=\"bar\">hello</bdo>")]
+#[case(elt::ruby([("foo", "bar").into()], ["hello".into()]), "<ruby foo=\"bar\">hello</ruby>")]
+#[case(elt::rt([("foo", "bar").into()], ["hello".into()]), "<rt foo=\"bar\">hello</rt>")]
+#[case(elt::rp([("foo", "bar").into()], ["hello".into()]), "<rp foo=\"bar\">hello</rp>")]
+#[case(elt::wbr([("foo", "bar").into()]), "<wbr foo=\"bar\">")]
+#[case(elt::ins([("foo", "bar").into()], ["hello".into()]), "<ins foo=\"bar\">hello</ins>")]
+#[case(elt::del([("foo", "bar").into()], ["hello".into()]), "<del foo=\"bar\">hello</del>")]
+#[case(elt::pre([("foo", "bar").into()], ["hello".into()]), "<pre foo=\"bar\">hello</pre>")]
+#[case(elt::blockquote([("foo", "bar").into()], ["hello".into()]), "<blockquote foo=\"bar\">hello</blockquote>")]
 #[case(elt::table([("foo", "bar").into()], ["hello".into()]), "<table foo=\"bar\">hello</table>")]
 #[case(elt::tr([("foo", "bar").into()], ["hello".into()]), "<tr foo=\"bar\">hello</tr>")]
 #[case(elt::td([("foo", "bar").into()], ["hello".into()]), "<td foo=\"bar\">hello</td>")]
@@ -217,3 +787,113 @@ fn script_should_be_escaped() {
     let string = elt::script([], "alert('</script>');").to_string();
     assert_eq!(string, "<script>alert('<\\/script>');</script>");
 }
+
+#[rstest]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid number for attribute step")]
+fn number_should_be_finite(#[values(f64::NAN, f64::INFINITY, f64::NEG_INFINITY)] value: f64) {
+    attr::step_number(value);
+}
+
+#[rstest]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid custom property name")]
+fn custom_property_name_should_be_valid(#[values("x", "--", "--a b", "--a;")] name: &'static str) {
+    let _ = Style::new().custom_property(name, "1");
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid pixel density")]
+fn srcset_should_reject_zero_density() {
+    Srcset::new().density("a.jpg", 0.0);
+}
+
+#[rstest]
+#[case(
+    elt::div([attr::style("color: red; margin: 0"), attr::id("x"), attr::style("margin: 1px")], []),
+    "<div style=\"color: red; margin: 1px\" id=\"x\"></div>"
+)]
+#[case(
+    elt::img([attr::style(Style::new().width(Length::px(1))), attr::alt(""), attr::style("height: 2px")]),
+    "<img style=\"width: 1px; height: 2px\" alt=\"\">"
+)]
+#[case(
+    elt::div([attr::class(["a", "b"]), attr::id("x"), attr::class_list(fun_html::classes!["b", "c" => true])], []),
+    "<div class=\"a b c\" id=\"x\"></div>"
+)]
+#[case(
+    elt::input([attr::class(["a"]), attr::style("color: red"), attr::class(["b"]), attr::style("margin: 0")]),
+    "<input class=\"a b\" style=\"color: red; margin: 0\">"
+)]
+#[case(
+    elt::div([attr::title("a"), attr::title("b")], []),
+    "<div title=\"a\" title=\"b\"></div>"
+)]
+fn should_merge_repeated_attributes(#[case] element: Element, #[case] expected: &str) {
+    assert_eq!(element.to_string(), expected);
+}
+
+#[test]
+fn should_render_responsive_picture() {
+    let element = elt::picture(
+        [],
+        [
+            elt::source([
+                attr::media("(min-width: 800px)"),
+                attr::srcset(Srcset::new().width("/wide.jpg", 1600)),
+                attr::sizes(Sizes::new("100vw")),
+            ]),
+            elt::img([
+                attr::src("/narrow.jpg"),
+                attr::alt("Landscape"),
+                attr::loading(Loading::Lazy),
+            ]),
+        ],
+    );
+    assert_eq!(
+        element.to_string(),
+        "<picture><source media=\"(min-width: 800px)\" srcset=\"/wide.jpg 1600w\" sizes=\"100vw\"><img src=\"/narrow.jpg\" alt=\"Landscape\" loading=\"lazy\"></picture>"
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "custom command should start with '--': rotate")]
+fn custom_command_should_start_with_dashes() {
+    attr::command(Command::Custom("rotate".into()));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid colspan: 0")]
+fn colspan_should_not_be_zero() {
+    attr::colspan(0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid rowspan: 65535")]
+fn rowspan_should_not_exceed_limit() {
+    attr::rowspan(65535);
+}
+
+#[rstest]
+#[case("")]
+#[case("my section")]
+#[case("a\tb")]
+fn autocomplete_section_should_be_a_single_token(#[case] name: &'static str) {
+    assert_eq!(
+        Autocomplete::new(Field::Name).section(name),
+        Err(InvalidSectionName(name.into()))
+    );
+}
+
+#[test]
+fn autocomplete_contact_fields_should_convert_to_fields() {
+    assert_eq!(
+        Autocomplete::new(ContactField::TelNational),
+        Autocomplete::new(Field::Contact(ContactField::TelNational))
+    );
+    assert_eq!(Autocomplete::from(ContactField::Impp).to_string(), "impp");
+}
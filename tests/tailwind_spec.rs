@@ -0,0 +1,102 @@
+#![cfg(feature = "tailwind")]
+
+use rstest::rstest;
+
+use fun_html::{
+    attr::{self, tailwind, ClassList},
+    classes, elt,
+};
+
+#[rstest]
+#[case("p-2 p-4", "p-4")]
+#[case("px-2 py-1 p-3", "p-3")]
+#[case("p-3 px-2", "p-3 px-2")]
+#[case("m-2 -m-4", "-m-4")]
+#[case("block flex hidden", "hidden")]
+#[case("absolute relative", "relative")]
+#[case("w-1/2 w-full h-4 size-8", "size-8")]
+#[case("text-red-500 text-lg text-blue-600", "text-lg text-blue-600")]
+#[case("text-left text-center", "text-center")]
+#[case("text-sm/6 text-base", "text-base")]
+#[case("leading-7 text-lg", "text-lg")]
+#[case("text-lg leading-7", "text-lg leading-7")]
+#[case("text-[14px] text-[#fff] text-xl", "text-[#fff] text-xl")]
+#[case("font-bold font-mono font-[600]", "font-mono font-[600]")]
+#[case(
+    "bg-red-500 bg-cover bg-[#000] bg-red-500/50",
+    "bg-cover bg-red-500/50"
+)]
+#[case("bg-[url(/a.png)] bg-none", "bg-none")]
+#[case(
+    "border border-2 border-red-500 border-dashed",
+    "border-2 border-red-500 border-dashed"
+)]
+#[case("border-x-2 border-l-4 border-4", "border-4")]
+#[case("border-t border-t-red-500", "border-t border-t-red-500")]
+#[case("rounded-t-lg rounded-tl-none rounded-md", "rounded-md")]
+#[case("rounded-md rounded-t-lg", "rounded-md rounded-t-lg")]
+#[case(
+    "ring ring-2 ring-blue-500 ring-offset-2 ring-offset-white",
+    "ring-2 ring-blue-500 ring-offset-2 ring-offset-white"
+)]
+#[case("shadow shadow-lg shadow-red-500", "shadow-lg shadow-red-500")]
+#[case("flex-row flex-col flex-1 flex-wrap", "flex-col flex-1 flex-wrap")]
+#[case("inset-x-0 left-2 inset-0", "inset-0")]
+#[case("overflow-x-auto overflow-hidden", "overflow-hidden")]
+#[case("hover:p-2 p-4 hover:p-3", "p-4 hover:p-3")]
+#[case("hover:focus:p-2 focus:hover:p-3", "focus:hover:p-3")]
+#[case("md:hover:p-2 hover:p-3", "md:hover:p-2 hover:p-3")]
+#[case("!p-2 p-4 !p-3", "p-4 !p-3")]
+#[case("p-2! !p-3", "!p-3")]
+#[case("[&>*]:p-2 [&>*]:p-4", "[&>*]:p-4")]
+#[case("btn p-2 btn p-4 card", "btn p-4 card")]
+#[case("content-center content-none", "content-center content-none")]
+#[case("grow grow-0 shrink", "grow-0 shrink")]
+#[case("underline no-underline uppercase", "no-underline uppercase")]
+#[case("col col-md-6", "col col-md-6")]
+#[case("row row-cols-2", "row row-cols-2")]
+#[case("text text-lg", "text text-lg")]
+#[case("p m p-2 m-2", "p m p-2 m-2")]
+#[case("order basis order-1 basis-1/2", "order basis order-1 basis-1/2")]
+#[case("p- p-2", "p- p-2")]
+#[case("border-t border-t-2", "border-t-2")]
+#[case("space-x-4 space-x-reverse", "space-x-4 space-x-reverse")]
+#[case("space-y-reverse space-y-2 space-y-4", "space-y-reverse space-y-4")]
+#[case("divide-x-2 divide-x-reverse divide-x", "divide-x-reverse divide-x")]
+#[case("divide-y-reverse divide-y-2", "divide-y-reverse divide-y-2")]
+fn should_resolve_conflicts(#[case] classes: &str, #[case] expected: &str) {
+    assert_eq!(tailwind::merge([classes]), expected);
+}
+
+#[test]
+fn class_tw_attribute_should_resolve_conflicts() {
+    assert_eq!(
+        attr::class_tw(["p-2", "text-sm", "p-4"]).to_string(),
+        r#"class="text-sm p-4""#
+    );
+}
+
+#[test]
+fn tailwind_class_list_should_resolve_conflicts() {
+    let mut list = ClassList::tailwind();
+    list.extend(["px-2", "bg-red-500", "card"]);
+    list.push("bg-blue-500");
+    list.push(("px-4", false));
+    assert_eq!(list.to_string(), "px-2 card bg-blue-500");
+}
+
+#[test]
+fn conflicts_should_only_be_resolved_when_asked_for() {
+    assert_eq!(
+        attr::class(["p-2", "p-4"]).to_string(),
+        r#"class="p-2 p-4""#
+    );
+    assert_eq!(
+        classes!["p-2", "p-4" => true, "p-8" => false].to_string(),
+        "p-2 p-4"
+    );
+    assert_eq!(
+        elt::div([attr::class(["btn", "p-2"]), attr::class(["p-4"])], []).normalize(),
+        elt::div([attr::class(["btn", "p-2", "p-4"])], [])
+    );
+}
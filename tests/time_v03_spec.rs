@@ -0,0 +1,76 @@
+#![cfg(feature = "time_v03")]
+
+use rstest::rstest;
+use time_v03::{Date, Duration, Month, PrimitiveDateTime, Time, UtcOffset};
+
+use fun_html::{attr, attr::HtmlDateTime, elt};
+
+fn date(year: i32, month: Month, day: u8) -> Date {
+    Date::from_calendar_date(year, month, day).unwrap()
+}
+
+fn time(hour: u8, minute: u8, second: u8, milli: u16) -> Time {
+    Time::from_hms_milli(hour, minute, second, milli).unwrap()
+}
+
+fn date_time(date: Date, time: Time) -> PrimitiveDateTime {
+    PrimitiveDateTime::new(date, time)
+}
+
+fn offset(hours: i8, minutes: i8) -> UtcOffset {
+    UtcOffset::from_hms(hours, minutes, 0).unwrap()
+}
+
+#[rstest]
+#[case(date(2024, Month::March, 1), "2024-03-01")]
+#[case(date(987, Month::December, 31), "0987-12-31")]
+#[case(time(9, 5, 0, 0), "09:05")]
+#[case(time(23, 59, 7, 0), "23:59:07")]
+#[case(time(12, 30, 5, 120), "12:30:05.12")]
+#[case(
+    date_time(date(2024, Month::March, 1), time(14, 30, 0, 0)),
+    "2024-03-01T14:30"
+)]
+#[case(
+    date_time(date(2024, Month::March, 1), time(14, 30, 0, 0)).assume_utc(),
+    "2024-03-01T14:30Z"
+)]
+#[case(
+    date_time(date(2024, Month::March, 1), time(14, 30, 0, 0)).assume_offset(offset(5, 45)),
+    "2024-03-01T14:30+05:45"
+)]
+#[case(
+    date_time(date(2024, Month::March, 1), time(14, 30, 0, 0)).assume_offset(offset(-3, 0)),
+    "2024-03-01T14:30-03:00"
+)]
+#[case(Duration::ZERO, "PT0S")]
+#[case(Duration::seconds(3661), "PT1H1M1S")]
+#[case(Duration::days(3) + Duration::hours(4), "P3DT4H")]
+#[case(Duration::milliseconds(250), "PT0.25S")]
+#[case(Duration::seconds(-1), "PT0S")]
+#[case(date(0, Month::January, 1), "0001-01-01")]
+#[case(date_time(date(-5, Month::June, 1), time(14, 30, 0, 0)), "0001-01-01T00:00")]
+#[case(
+    date_time(date(2024, Month::March, 1), time(14, 30, 0, 0)).assume_offset(UtcOffset::from_hms(1, 0, 30).unwrap()),
+    "2024-03-01T13:29:30Z"
+)]
+fn should_convert_to_html_datetime(#[case] value: impl Into<HtmlDateTime>, #[case] expected: &str) {
+    assert_eq!(value.into().to_string(), expected);
+}
+
+#[test]
+fn should_render_date_attributes() {
+    assert_eq!(
+        elt::input([
+            attr::type_time(),
+            attr::min_date(time(9, 0, 0, 0)),
+            attr::max_date(time(17, 30, 0, 0)),
+        ])
+        .to_string(),
+        r#"<input type="time" min="09:00" max="17:30">"#
+    );
+    assert_eq!(
+        elt::time_datetime(Duration::minutes(5), ["5 minutes".into()]).to_string(),
+        r#"<time datetime="PT5M">5 minutes</time>"#
+    );
+}
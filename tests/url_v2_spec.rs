@@ -0,0 +1,21 @@
+#![cfg(feature = "url_v2")]
+
+use url_v2::Url;
+
+use fun_html::{
+    attr::{self, Href},
+    elt,
+};
+
+#[test]
+fn should_accept_url() {
+    let url = Url::parse("https://example.com/search?q=a b").unwrap();
+    assert_eq!(
+        attr::href(Href::from(&url)).to_string(),
+        r#"href="https://example.com/search?q=a%20b""#
+    );
+    assert_eq!(
+        elt::img([attr::src(Href::from(url.join("/logo.png").unwrap()))]).to_string(),
+        r#"<img src="https://example.com/logo.png">"#
+    );
+}
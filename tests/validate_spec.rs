@@ -0,0 +1,267 @@
+use rstest::rstest;
+
+use fun_html::{
+    attr::{self, Command, Scope},
+    elt, html,
+    validate::{
+        AttributeChecker, AttributeWarning, AttributeWarningKind, Violation, ViolationKind,
+    },
+    Element,
+};
+
+fn invalid_child(path: &str, parent: &'static str, child: &'static str) -> Violation {
+    Violation {
+        path: path.into(),
+        kind: ViolationKind::InvalidChild { parent, child },
+    }
+}
+
+fn invalid_descendant(path: &str, ancestor: &'static str, descendant: &'static str) -> Violation {
+    Violation {
+        path: path.into(),
+        kind: ViolationKind::InvalidDescendant {
+            ancestor,
+            descendant,
+        },
+    }
+}
+
+#[rstest]
+#[case(elt::div([], []))]
+#[case(elt::div([], [elt::p([], ["hello".into()]), elt::ul([], [elt::li([], [elt::div([], [])])])]))]
+#[case(elt::ul([], [" ".into(), elt::li([], []), elt::script([], ""), "\n".into()]))]
+#[case(elt::p([], [elt::span([], ["a".into()]), elt::a([attr::href("/")], ["b".into()]), elt::br([])]))]
+#[case(elt::div([], [elt::a([attr::href("/")], [elt::div([], [])])]))]
+#[case(elt::table([], [elt::thead([], [elt::tr([], [elt::th([], [])])]), elt::tbody([], [elt::tr([], [elt::td([], [])])])]))]
+#[case(elt::select([], [elt::option([], ["a".into()])]))]
+#[case(elt::form([], [elt::fieldset([], [elt::legend([], []), elt::input([])])]))]
+#[case(elt::button([], [elt::span([], [])]))]
+#[case(elt::a([attr::href("/")], [elt::input([attr::type_hidden()])]))]
+#[case(elt::p([], [Element::new("my-component", [], [elt::div([], [])])]))]
+#[case(elt::p([], [elt::raw("<div></div>")]))]
+#[case(elt::body([], [elt::link_stylesheet("/style.css")]))]
+fn should_accept_valid_tree(#[case] element: Element) {
+    assert_eq!(element.validate(), []);
+}
+
+#[rstest]
+#[case(
+    elt::ul([], [elt::li([], []), elt::div([], [])]),
+    vec![invalid_child("ul > div", "ul", "div")],
+)]
+#[case(
+    elt::ul([], ["hello".into()]),
+    vec![invalid_child("ul > #text", "ul", "#text")],
+)]
+#[case(
+    elt::p([], [elt::div([], [])]),
+    vec![invalid_child("p > div", "p", "div")],
+)]
+#[case(
+    elt::p([], [elt::a([attr::href("/")], [elt::div([], [])])]),
+    vec![invalid_child("p > a > div", "a", "div")],
+)]
+#[case(
+    elt::table([], [elt::td([], [])]),
+    vec![invalid_child("table > td", "table", "td")],
+)]
+#[case(
+    elt::head([], [elt::div([], [])]),
+    vec![invalid_child("head > div", "head", "div")],
+)]
+#[case(
+    elt::select([], [elt::option([], [elt::span([], [])])]),
+    vec![invalid_child("select > option > span", "option", "span")],
+)]
+#[case(
+    elt::form([], [elt::div([], [elt::form([], [])])]),
+    vec![invalid_descendant("form > div > form", "form", "form")],
+)]
+#[case(
+    elt::a([attr::href("/")], [elt::button([], [])]),
+    vec![invalid_descendant("a > button", "a", "button")],
+)]
+#[case(
+    elt::a([], [elt::a([], [])]),
+    vec![invalid_descendant("a > a", "a", "a")],
+)]
+#[case(
+    elt::button([], [elt::input([])]),
+    vec![invalid_descendant("button > input", "button", "input")],
+)]
+#[case(
+    elt::header([], [elt::div([], [elt::footer([], [])])]),
+    vec![invalid_descendant("header > div > footer", "header", "footer")],
+)]
+#[case(
+    elt::th([], [elt::h1([], [])]),
+    vec![invalid_descendant("th > h1", "th", "h1")],
+)]
+#[case(
+    elt::label([], [elt::div([], [elt::label([], [])])]),
+    vec![
+        invalid_child("label > div", "label", "div"),
+        invalid_descendant("label > div > label", "label", "label"),
+    ],
+)]
+fn should_report_violations(#[case] element: Element, #[case] expected: Vec<Violation>) {
+    assert_eq!(element.validate(), expected);
+}
+
+#[test]
+fn should_validate_document() {
+    let doc = html(
+        [],
+        [
+            elt::head([], [elt::title([], "hello")]),
+            elt::body([], [elt::p([], [elt::p([], [])])]),
+            elt::div([], []),
+        ],
+    );
+    let violations: Vec<String> = doc.validate().iter().map(ToString::to_string).collect();
+    assert_eq!(
+        violations,
+        [
+            "html > body > p > p: `p` is not allowed in `p`",
+            "html > div: `div` is not allowed in `html`",
+        ]
+    );
+}
+
+fn unknown_attribute(path: &str, tag: &'static str, attribute: &str) -> AttributeWarning {
+    AttributeWarning {
+        path: path.into(),
+        kind: AttributeWarningKind::UnknownAttribute {
+            tag,
+            attribute: attribute.into(),
+        },
+    }
+}
+
+fn invalid_value(path: &str, attribute: &str, value: &str) -> AttributeWarning {
+    AttributeWarning {
+        path: path.into(),
+        kind: AttributeWarningKind::InvalidValue {
+            attribute: attribute.into(),
+            value: value.into(),
+        },
+    }
+}
+
+#[rstest]
+#[case(elt::div([attr::id("a"), attr::class(["b"]), attr::lang("en"), attr::style("")], []))]
+#[case(elt::a([attr::href("/"), attr::target_blank(), attr::download()], []))]
+#[case(elt::input([attr::type_email(), attr::name("email"), attr::required(), attr::autocomplete("email")]))]
+#[case(elt::input([attr::type_("DATETIME-LOCAL")]))]
+#[case(elt::form([attr::method_post(), attr::action("/")], []))]
+#[case(elt::form([("method", "dialog").into()], []))]
+#[case(elt::script([attr::src("/a.js"), attr::defer(), attr::crossorigin_anonymous()], ""))]
+#[case(elt::ol([("type", "A").into()], []))]
+#[case(elt::div([("data-id", "1").into(), ("aria-label", "x").into(), ("onclick", "f()").into()], []))]
+#[case(elt::button([("hx-post", "/").into(), ("x-on:click", "f()").into(), ("@click", "f()").into(), (":class", "c").into()], []))]
+#[case(Element::new("my-component", [("anything", "goes").into()], []))]
+#[case(elt::div([("hidden", "until-found").into(), ("dir", "rtl").into()], []))]
+#[case(elt::button([attr::commandfor("d"), attr::command(Command::ShowModal)], []))]
+#[case(elt::button([attr::commandfor("d"), attr::command(Command::Custom("--rotate".into()))], []))]
+#[case(elt::details([attr::open(), attr::name("faq")], [elt::summary([], [])]))]
+#[case(elt::td([attr::colspan(1000), attr::rowspan(0)], []))]
+#[case(elt::th([attr::scope(Scope::Col), attr::abbr("Qty"), attr::headers("a")], []))]
+#[case(elt::meta([("property", "og:title").into(), attr::content("Title")]))]
+#[case(elt::col([attr::span(3)]))]
+fn should_accept_valid_attributes(#[case] element: Element) {
+    assert_eq!(element.check_attributes(), []);
+}
+
+#[rstest]
+#[case(
+    elt::div([attr::href("/")], []),
+    vec![unknown_attribute("div", "div", "href")],
+)]
+#[case(
+    elt::input([attr::rows(3)]),
+    vec![unknown_attribute("input", "input", "rows")],
+)]
+#[case(
+    elt::div([], [elt::img([attr::src("a.png"), attr::action("/")])]),
+    vec![unknown_attribute("div > img", "img", "action")],
+)]
+#[case(
+    elt::input([attr::type_("txt")]),
+    vec![invalid_value("input", "type", "txt")],
+)]
+#[case(
+    elt::button([attr::type_("text")], []),
+    vec![invalid_value("button", "type", "text")],
+)]
+#[case(
+    elt::form([("method", "put").into()], []),
+    vec![invalid_value("form", "method", "put")],
+)]
+#[case(
+    elt::ol([("type", "b").into()], []),
+    vec![invalid_value("ol", "type", "b")],
+)]
+#[case(
+    elt::p([("dir", "up").into(), ("draggable", "").into()], []),
+    vec![invalid_value("p", "dir", "up"), invalid_value("p", "draggable", "")],
+)]
+#[case(
+    elt::button([("command", "rotate").into()], []),
+    vec![invalid_value("button", "command", "rotate")],
+)]
+#[case(
+    elt::td([("colspan", "1001").into(), ("rowspan", "65535").into()], []),
+    vec![invalid_value("td", "colspan", "1001"), invalid_value("td", "rowspan", "65535")],
+)]
+#[case(
+    elt::th([("colspan", "0").into(), ("rowspan", "+1").into()], []),
+    vec![invalid_value("th", "colspan", "0"), invalid_value("th", "rowspan", "+1")],
+)]
+#[case(
+    elt::colgroup([("span", "two").into()], []),
+    vec![invalid_value("colgroup", "span", "two")],
+)]
+fn should_warn_about_invalid_attributes(
+    #[case] element: Element,
+    #[case] expected: Vec<AttributeWarning>,
+) {
+    assert_eq!(element.check_attributes(), expected);
+}
+
+#[test]
+fn should_allow_custom_prefixes() {
+    let element = elt::div([("v-if", "visible").into(), ("hx-get", "/").into()], []);
+    assert_eq!(
+        AttributeChecker::default().check(&element),
+        [unknown_attribute("div", "div", "v-if")]
+    );
+    assert_eq!(
+        AttributeChecker::default()
+            .allow_prefix("v-")
+            .check(&element),
+        []
+    );
+    assert_eq!(
+        AttributeChecker::strict()
+            .allow_prefix("v-")
+            .check(&element),
+        [unknown_attribute("div", "div", "hx-get")]
+    );
+}
+
+#[test]
+fn should_check_document_attributes() {
+    let doc = html(
+        [attr::lang("en")],
+        [elt::head([], []), elt::body([attr::rows(3)], [])],
+    );
+    let warnings: Vec<String> = doc
+        .check_attributes()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        warnings,
+        ["html > body: attribute `rows` is not allowed on `body`"]
+    );
+}